@@ -4244,7 +4244,7 @@ describe('composition', () => {
       const result = composeAsFed2Subgraphs([invalidApplication]);
       expect(errors(result)[0]).toEqual([
         "INVALID_GRAPHQL",
-        "[invalidApplication] Directive \"@authenticated\" may not be used on ENUM_VALUE.",
+        "[invalidApplication] Directive \"@authenticated\" may not be used on ENUM_VALUE. It can only be used on FIELD_DEFINITION, OBJECT, INTERFACE, SCALAR or ENUM.",
       ]);
     });
   });
@@ -4596,7 +4596,7 @@ describe('composition', () => {
         const result = composeAsFed2Subgraphs([invalidApplication]);
         expect(errors(result)[0]).toEqual([
           "INVALID_GRAPHQL",
-          `[invalidApplication] Directive \"${directiveName}\" may not be used on ENUM_VALUE.`,
+          `[invalidApplication] Directive \"${directiveName}\" may not be used on ENUM_VALUE. It can only be used on FIELD_DEFINITION, OBJECT, INTERFACE, SCALAR or ENUM.`,
         ]);
       });
     });
@@ -5092,6 +5092,48 @@ describe('@source* directives', () => {
       expect(result.errors ?? []).toEqual([]);
     });
 
+    it('allows @sourceField on fields of root types with non-default names', () => {
+      const result = composeServices([{
+        name: 'renamedRoots',
+        typeDefs: gql`
+          extend schema
+            @link(url: "https://specs.apollo.dev/federation/v2.7", import: ["@key"])
+            @link(url: "https://specs.apollo.dev/source/v0.1", import: [
+              "@sourceAPI"
+              "@sourceField"
+            ])
+            @sourceAPI(
+              name: "A"
+              http: { baseURL: "https://api.a.com/v1" }
+            )
+          {
+            query: RootQuery
+            mutation: RootMutation
+          }
+
+          type RootQuery {
+            resources: [Resource!]! @sourceField(
+              api: "A"
+              http: { GET: "/resources" }
+            )
+          }
+
+          type RootMutation {
+            deleteResource(id: ID!): Boolean @sourceField(
+              api: "A"
+              http: { DELETE: "/resources/{id}" }
+            )
+          }
+
+          type Resource {
+            id: ID!
+            description: String!
+          }
+        `,
+      }]);
+      expect(result.errors ?? []).toEqual([]);
+    });
+
     it('bad schema composes with validation errors', () => {
       const result = composeServices([{
         name: 'bad',
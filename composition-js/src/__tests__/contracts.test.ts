@@ -0,0 +1,87 @@
+import { contractAPISchema, errorCauses } from '@apollo/federation-internals';
+import gql from 'graphql-tag';
+import { assertCompositionSuccess, composeAsFed2Subgraphs } from './testHelper';
+
+function composeSupergraph(...typeDefs: ReturnType<typeof gql>[]) {
+  const result = composeAsFed2Subgraphs(typeDefs.map((t, i) => ({ name: `subgraph${i}`, typeDefs: t })));
+  assertCompositionSuccess(result);
+  return result.schema;
+}
+
+describe('contracts', () => {
+  const supergraph = composeSupergraph(
+    gql`
+      type Query {
+        products: [Product] @tag(name: "public")
+        internalStats: Stats @tag(name: "internal")
+      }
+
+      type Product @key(fields: "id") @tag(name: "public") {
+        id: ID!
+        name: String
+        cost: Int @tag(name: "internal")
+        status(verbose: Boolean @tag(name: "internal")): Status
+      }
+
+      type Stats {
+        count: Int
+      }
+
+      enum Status {
+        AVAILABLE
+        DISCONTINUED @tag(name: "internal")
+      }
+    `,
+  );
+
+  it('removes excluded elements', () => {
+    const contract = contractAPISchema(supergraph, { exclude: ['internal'] });
+    expect(contract.elementByCoordinate('Query.products')).toBeDefined();
+    expect(contract.elementByCoordinate('Query.internalStats')).toBeUndefined();
+    expect(contract.elementByCoordinate('Product.name')).toBeDefined();
+    expect(contract.elementByCoordinate('Product.cost')).toBeUndefined();
+    expect(contract.elementByCoordinate('Product.status')).toBeDefined();
+    expect(contract.elementByCoordinate('Product.status(verbose:)')).toBeUndefined();
+    expect(contract.elementByCoordinate('Status.AVAILABLE')).toBeDefined();
+    expect(contract.elementByCoordinate('Status.DISCONTINUED')).toBeUndefined();
+    // Untagged types are kept when only excluding.
+    expect(contract.type('Stats')).toBeDefined();
+  });
+
+  it('only keeps included elements', () => {
+    const contract = contractAPISchema(supergraph, { include: ['public'] });
+    expect(contract.elementByCoordinate('Query.products')).toBeDefined();
+    expect(contract.elementByCoordinate('Query.internalStats')).toBeUndefined();
+    expect(contract.type('Stats')).toBeUndefined();
+    // The fields of included types are kept, unless excluded.
+    expect(contract.elementByCoordinate('Product.cost')).toBeDefined();
+    expect(contractAPISchema(supergraph, { include: ['public'], exclude: ['internal'] }).elementByCoordinate('Product.cost')).toBeUndefined();
+  });
+
+  it('does not modify the supergraph', () => {
+    contractAPISchema(supergraph, { exclude: ['internal'] });
+    expect(supergraph.toAPISchema().elementByCoordinate('Product.cost')).toBeDefined();
+  });
+
+  it('errors when a kept element references a removed one', () => {
+    const withDanglingReference = composeSupergraph(
+      gql`
+        type Query {
+          products: [Product]
+        }
+
+        type Product @tag(name: "internal") {
+          id: ID!
+        }
+      `,
+    );
+    try {
+      contractAPISchema(withDanglingReference, { exclude: ['internal'] });
+      throw new Error('should have thrown');
+    } catch (e) {
+      const messages = errorCauses(e)?.map((cause) => cause.message);
+      expect(messages).toBeDefined();
+      expect(messages!.some((m) => m.includes('Query.products'))).toBe(true);
+    }
+  });
+});
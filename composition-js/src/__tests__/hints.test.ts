@@ -2,6 +2,7 @@ import { asFed2SubgraphDocument, buildSubgraph, Subgraphs } from '@apollo/federa
 import { DocumentNode } from 'graphql';
 import gql from 'graphql-tag';
 import {
+  diagnosticFromHint,
   HintCodeDefinition,
   HINTS,
 } from '../hints';
@@ -153,6 +154,12 @@ test('hints on merging field with nullable and non-nullable types', () => {
     + 'will use type "String" (from subgraph "Subgraph1") in supergraph but "T.f" has subtype "String!" in subgraph "Subgraph2".',
     'T.f'
   );
+
+  const diagnostic = diagnosticFromHint(result.hints!.find((h) => h.definition.code === HINTS.INCONSISTENT_BUT_COMPATIBLE_FIELD_TYPE.code)!);
+  expect(diagnostic.code).toBe('INCONSISTENT_BUT_COMPATIBLE_FIELD_TYPE');
+  expect(diagnostic.severity).toBe('note');
+  expect(diagnostic.coordinate).toBe('T.f');
+  expect(diagnostic.locations.map((l) => l.subgraph).sort()).toStrictEqual(['Subgraph1', 'Subgraph2']);
 })
 
 test('hints on merging field with subtype types', () => {
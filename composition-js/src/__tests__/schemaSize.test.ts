@@ -0,0 +1,70 @@
+import gql from 'graphql-tag';
+import { HINTS } from '../hints';
+import { assertCompositionSuccess, composeAsFed2Subgraphs, errors } from './testHelper';
+
+const subgraphA = {
+  name: 'subgraphA',
+  typeDefs: gql`
+    type Query {
+      t: T
+    }
+
+    type T @key(fields: "k") {
+      k: ID
+    }
+  `,
+};
+
+const subgraphB = {
+  name: 'subgraphB',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      v: Int
+    }
+
+    input I {
+      x: Int
+    }
+  `,
+};
+
+describe('schema size limits', () => {
+  it('composes without size hints when within the limits', () => {
+    const result = composeAsFed2Subgraphs([subgraphA, subgraphB], {
+      schemaSizeLimits: {
+        soft: { maxTypes: 3, maxFields: 4 },
+        hard: { maxTypes: 3, maxFields: 4 },
+      },
+    });
+    assertCompositionSuccess(result);
+    expect(result.hints.filter((h) => h.definition === HINTS.SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED)).toHaveLength(0);
+  });
+
+  it('raises hints when exceeding soft limits', () => {
+    const result = composeAsFed2Subgraphs([subgraphA, subgraphB], {
+      schemaSizeLimits: {
+        soft: { maxTypes: 2, maxFields: 3 },
+      },
+    });
+    assertCompositionSuccess(result);
+    const sizeHints = result.hints.filter((h) => h.definition === HINTS.SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED);
+    expect(sizeHints.map((h) => h.message)).toStrictEqual([
+      'The supergraph exceeds the recommended size: it has 3 types (limit: 2).',
+      'The supergraph exceeds the recommended size: it has 4 fields (limit: 3).',
+    ]);
+  });
+
+  it('errors when exceeding hard limits', () => {
+    const result = composeAsFed2Subgraphs([subgraphA, subgraphB], {
+      schemaSizeLimits: {
+        soft: { maxSdlBytes: 10 },
+        hard: { maxTypes: 2, maxSdlBytes: 10 },
+      },
+    });
+    expect(result.errors).toBeDefined();
+    expect(errors(result).map(([code]) => code)).toStrictEqual(['SCHEMA_SIZE_LIMIT_EXCEEDED', 'SCHEMA_SIZE_LIMIT_EXCEEDED']);
+    expect(errors(result)[0][1]).toBe('The supergraph exceeds the maximum allowed size: it has 3 types (limit: 2).');
+    expect(errors(result)[1][1]).toMatch(/^The supergraph exceeds the maximum allowed size: its SDL is \d+ bytes \(limit: 10\)\.$/);
+  });
+});
@@ -0,0 +1,135 @@
+import { Supergraph } from '@apollo/federation-internals';
+import gql from 'graphql-tag';
+import { checkSubgraph } from '../subgraphCheck';
+import { asFed2Service, assertCompositionSuccess, composeAsFed2Subgraphs } from './testHelper';
+
+const subgraphA = {
+  name: 'subgraphA',
+  typeDefs: gql`
+    type Query {
+      t: T
+    }
+
+    type T @key(fields: "k") {
+      k: ID
+    }
+  `,
+};
+
+const subgraphB = {
+  name: 'subgraphB',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      b: Int
+      old: String
+    }
+  `,
+};
+
+function supergraphOf(): Supergraph {
+  const result = composeAsFed2Subgraphs([subgraphA, subgraphB]);
+  assertCompositionSuccess(result);
+  return Supergraph.build(result.supergraphSdl);
+}
+
+describe('checkSubgraph', () => {
+  it('accepts compatible updates', () => {
+    const result = checkSubgraph(supergraphOf(), asFed2Service({
+      name: 'subgraphB',
+      typeDefs: gql`
+        type T @key(fields: "k") {
+          k: ID
+          b: Int
+          old: String
+          added: Boolean
+        }
+      `,
+    }));
+    expect(result.errors).toHaveLength(0);
+    expect(result.changes.map((c) => [c.kind, c.severity, c.coordinate])).toStrictEqual([
+      ['FIELD_ADDED', 'SAFE', 'T.added'],
+    ]);
+    expect(result.supergraphSdl).toBeDefined();
+  });
+
+  it('reports breaking changes and broken operations', () => {
+    const usingOld = gql`{ t { k old } }`;
+    const notUsingOld = gql`{ t { k b } }`;
+    const alreadyInvalid = gql`{ t { unknown } }`;
+    const result = checkSubgraph(
+      supergraphOf(),
+      asFed2Service({
+        name: 'subgraphB',
+        typeDefs: gql`
+          type T @key(fields: "k") {
+            k: ID
+            b: Int
+          }
+        `,
+      }),
+      { operations: [usingOld, notUsingOld, alreadyInvalid] },
+    );
+    expect(result.errors).toHaveLength(0);
+    expect(result.changes.map((c) => [c.kind, c.severity, c.coordinate])).toStrictEqual([
+      ['FIELD_REMOVED', 'BREAKING', 'T.old'],
+    ]);
+    expect(result.brokenOperations).toHaveLength(1);
+    expect(result.brokenOperations[0].operation).toBe(usingOld);
+    expect(result.brokenOperations[0].errors.map((e) => e.message)).toStrictEqual([
+      'Cannot query field "old" on type "T".',
+    ]);
+  });
+
+  it('reports composition errors', () => {
+    const result = checkSubgraph(supergraphOf(), asFed2Service({
+      name: 'subgraphB',
+      typeDefs: gql`
+        type Query {
+          t: T
+        }
+
+        type T @key(fields: "k") {
+          k: ID
+          b: Int
+        }
+      `,
+    }));
+    expect(result.errors.map((e) => e.extensions.code)).toContain('INVALID_FIELD_SHARING');
+    expect(result.changes).toHaveLength(0);
+    expect(result.supergraphSdl).toBeUndefined();
+  });
+
+  it('reports each error of an invalid subgraph', () => {
+    const result = checkSubgraph(supergraphOf(), asFed2Service({
+      name: 'subgraphB',
+      typeDefs: gql`
+        type T @key(fields: "k") {
+          k: ID
+          b: Unknown1
+          c: Unknown2
+        }
+      `,
+    }));
+    expect(result.errors.map((e) => e.message)).toStrictEqual([
+      '[subgraphB] Unknown type Unknown1',
+      '[subgraphB] Unknown type Unknown2',
+    ]);
+    expect(result.changes).toHaveLength(0);
+  });
+
+  it('handles new subgraphs', () => {
+    const result = checkSubgraph(supergraphOf(), asFed2Service({
+      name: 'subgraphC',
+      typeDefs: gql`
+        type T @key(fields: "k") {
+          k: ID
+          c: String
+        }
+      `,
+    }));
+    expect(result.errors).toHaveLength(0);
+    expect(result.changes.map((c) => c.coordinate)).toStrictEqual(['T.c']);
+    expect(Supergraph.build(result.supergraphSdl!).subgraphs().names()).toContain('subgraphC');
+  });
+});
@@ -0,0 +1,131 @@
+import { Subgraphs, subgraphsFromServiceList } from '@apollo/federation-internals';
+import fs from 'fs';
+import gql from 'graphql-tag';
+import os from 'os';
+import path from 'path';
+import { gunzipSync, gzipSync } from 'zlib';
+import { compose } from '../compose';
+import {
+  createSupergraphArtifact,
+  decodeSupergraphArtifact,
+  encodeSupergraphArtifact,
+  sha256Hex,
+  supergraphArtifactId,
+  SUPERGRAPH_ARTIFACT_FORMAT_VERSION,
+} from '../supergraphArtifact';
+import {
+  deserializeSupergraphArtifact,
+  readSupergraphArtifact,
+  serializeSupergraphArtifact,
+  writeSupergraphArtifact,
+} from '../supergraphArtifactFiles';
+import { asFed2Service, assertCompositionSuccess } from './testHelper';
+
+const subgraphA = {
+  name: 'subgraphA',
+  url: 'https://subgraphA',
+  typeDefs: gql`
+    type Query {
+      t: T
+    }
+
+    type T @key(fields: "k") {
+      k: ID
+    }
+  `,
+};
+
+const subgraphB = {
+  name: 'subgraphB',
+  url: 'https://subgraphB',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      b: Int
+    }
+  `,
+};
+
+function buildSubgraphs(): Subgraphs {
+  const subgraphs = subgraphsFromServiceList([subgraphA, subgraphB].map((s) => asFed2Service(s)));
+  if (Array.isArray(subgraphs)) {
+    throw new Error(`Unexpected errors: ${subgraphs.join('\n')}`);
+  }
+  return subgraphs;
+}
+
+function composeArtifact() {
+  const subgraphs = buildSubgraphs();
+  const result = compose(subgraphs);
+  assertCompositionSuccess(result);
+  return { result, artifact: createSupergraphArtifact(result, subgraphs, { builtBy: 'test' }) };
+}
+
+describe('supergraph artifacts', () => {
+  it('records the composition inputs and outputs', () => {
+    const { result, artifact } = composeArtifact();
+    const subgraphs = buildSubgraphs();
+
+    expect(artifact.formatVersion).toBe(SUPERGRAPH_ARTIFACT_FORMAT_VERSION);
+    expect(artifact.supergraphSdl).toBe(result.supergraphSdl);
+    expect(artifact.supergraphHash).toBe(sha256Hex(result.supergraphSdl));
+    expect(artifact.subgraphs).toStrictEqual([
+      { name: 'subgraphA', url: 'https://subgraphA', sdlHash: sha256Hex(subgraphs.get('subgraphA')!.toString()) },
+      { name: 'subgraphB', url: 'https://subgraphB', sdlHash: sha256Hex(subgraphs.get('subgraphB')!.toString()) },
+    ]);
+    expect(artifact.hints.map((h) => h.code)).toStrictEqual(result.hints.map((h) => h.definition.code));
+    expect(artifact.metadata).toStrictEqual({ builtBy: 'test' });
+  });
+
+  it('round-trips through encoding', () => {
+    const { artifact } = composeArtifact();
+    expect(decodeSupergraphArtifact(encodeSupergraphArtifact(artifact))).toStrictEqual(artifact);
+  });
+
+  it('round-trips through serialization', () => {
+    const { artifact } = composeArtifact();
+    const deserialized = deserializeSupergraphArtifact(serializeSupergraphArtifact(artifact));
+    expect(deserialized).toStrictEqual(artifact);
+    expect(supergraphArtifactId(deserialized)).toBe(supergraphArtifactId(artifact));
+  });
+
+  it('is content-addressed', () => {
+    const { artifact } = composeArtifact();
+    const { artifact: other } = composeArtifact();
+    expect(supergraphArtifactId(other)).toBe(supergraphArtifactId(artifact));
+
+    const withDifferentMetadata = { ...artifact, metadata: { builtBy: 'someone else' } };
+    expect(supergraphArtifactId(withDifferentMetadata)).not.toBe(supergraphArtifactId(artifact));
+  });
+
+  it('rejects tampered artifacts', () => {
+    const { artifact } = composeArtifact();
+    const envelope = JSON.parse(gunzipSync(serializeSupergraphArtifact(artifact)).toString('utf8'));
+    const tamperedPayload = envelope.payload.replace('subgraphB', 'subgraphC');
+    const tampered = gzipSync(JSON.stringify({ checksum: envelope.checksum, payload: tamperedPayload }));
+    expect(() => deserializeSupergraphArtifact(tampered)).toThrow(/checksum mismatch/);
+  });
+
+  it('rejects data that is not an artifact', () => {
+    expect(() => deserializeSupergraphArtifact(Buffer.from('not an artifact'))).toThrow(/Invalid supergraph artifact/);
+    expect(() => decodeSupergraphArtifact('not an artifact')).toThrow(/Invalid supergraph artifact/);
+  });
+
+  it('rejects unsupported format versions', () => {
+    const { artifact } = composeArtifact();
+    const fromTheFuture = { ...artifact, formatVersion: SUPERGRAPH_ARTIFACT_FORMAT_VERSION + 1 };
+    expect(() => deserializeSupergraphArtifact(serializeSupergraphArtifact(fromTheFuture))).toThrow(/Unsupported supergraph artifact format version/);
+  });
+
+  it('can be written to and read from a file', () => {
+    const { artifact } = composeArtifact();
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'supergraph-artifact-'));
+    try {
+      const file = path.join(dir, 'supergraph.artifact');
+      writeSupergraphArtifact(file, artifact);
+      expect(readSupergraphArtifact(file)).toStrictEqual(artifact);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
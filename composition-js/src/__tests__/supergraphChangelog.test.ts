@@ -0,0 +1,117 @@
+import { ServiceDefinition, subgraphsFromServiceList } from '@apollo/federation-internals';
+import gql from 'graphql-tag';
+import { compose } from '../compose';
+import { createSupergraphArtifact, SupergraphArtifact, supergraphArtifactId } from '../supergraphArtifact';
+import {
+  supergraphArtifactChangelog,
+  supergraphChangelogToJSON,
+  supergraphChangelogToMarkdown,
+} from '../supergraphChangelog';
+import { asFed2Service, assertCompositionSuccess } from './testHelper';
+
+function artifactOf(services: ServiceDefinition[]): SupergraphArtifact {
+  const subgraphs = subgraphsFromServiceList(services.map((s) => asFed2Service(s)));
+  if (Array.isArray(subgraphs)) {
+    throw new Error(`Unexpected errors: ${subgraphs.join('\n')}`);
+  }
+  const result = compose(subgraphs);
+  assertCompositionSuccess(result);
+  return createSupergraphArtifact(result, subgraphs);
+}
+
+const subgraphA = {
+  name: 'subgraphA',
+  typeDefs: gql`
+    type Query {
+      t: T
+    }
+
+    type T @key(fields: "k") {
+      k: ID
+    }
+  `,
+};
+
+const subgraphB = {
+  name: 'subgraphB',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      b: Int
+      old: String
+    }
+  `,
+};
+
+const updatedSubgraphB = {
+  name: 'subgraphB',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      b: Int!
+      new: String
+    }
+  `,
+};
+
+const subgraphC = {
+  name: 'subgraphC',
+  typeDefs: gql`
+    type T @key(fields: "k") {
+      k: ID
+      c: E
+    }
+
+    enum E {
+      V1
+      V2
+    }
+  `,
+};
+
+describe('supergraph changelog', () => {
+  it('attributes changes to the subgraphs that changed', () => {
+    const previous = artifactOf([subgraphA, subgraphB]);
+    const next = artifactOf([subgraphA, updatedSubgraphB, subgraphC]);
+    const changelog = supergraphArtifactChangelog(previous, next);
+
+    expect(changelog.from).toBe(supergraphArtifactId(previous));
+    expect(changelog.to).toBe(supergraphArtifactId(next));
+    expect(changelog.subgraphChanges.map((c) => [c.subgraph, c.kind])).toStrictEqual([
+      ['subgraphB', 'UPDATED'],
+      ['subgraphC', 'ADDED'],
+    ]);
+    expect(changelog.schemaChanges.map((c) => [c.severity, c.kind, c.coordinate, c.subgraphs])).toStrictEqual([
+      ['BREAKING', 'FIELD_REMOVED', 'T.old', ['subgraphB']],
+      ['SAFE', 'TYPE_ADDED', 'E', ['subgraphC']],
+      ['SAFE', 'FIELD_TYPE_CHANGED', 'T.b', ['subgraphB']],
+      ['SAFE', 'FIELD_ADDED', 'T.c', ['subgraphC']],
+      ['SAFE', 'FIELD_ADDED', 'T.new', ['subgraphB']],
+    ]);
+    expect(changelog.schemaChanges.find((c) => c.coordinate === 'T.b')!.message).toBe('T.b changed type from Int to Int!.');
+  });
+
+  it('is empty for identical artifacts', () => {
+    const artifact = artifactOf([subgraphA, subgraphB]);
+    const changelog = supergraphArtifactChangelog(artifact, artifact);
+    expect(changelog.subgraphChanges).toHaveLength(0);
+    expect(changelog.schemaChanges).toHaveLength(0);
+  });
+
+  it('renders as markdown', () => {
+    const previous = artifactOf([subgraphA, subgraphB]);
+    const next = artifactOf([subgraphA, updatedSubgraphB]);
+    const markdown = supergraphChangelogToMarkdown(supergraphArtifactChangelog(previous, next));
+    expect(markdown).toContain('- `subgraphB`: updated');
+    expect(markdown).toContain('- **Breaking** `T.old`: T.old was removed. (from `subgraphB`)');
+    expect(markdown).toContain('- **Safe** `T.b`: T.b changed type from Int to Int!. (from `subgraphB`)');
+    expect(markdown).toContain('- **Safe** `T.new`: T.new was added. (from `subgraphB`)');
+  });
+
+  it('renders as JSON', () => {
+    const previous = artifactOf([subgraphA, subgraphB]);
+    const next = artifactOf([subgraphA, updatedSubgraphB]);
+    const changelog = supergraphArtifactChangelog(previous, next);
+    expect(JSON.parse(supergraphChangelogToJSON(changelog))).toStrictEqual(JSON.parse(JSON.stringify(changelog)));
+  });
+});
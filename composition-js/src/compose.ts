@@ -17,6 +17,7 @@ import { buildFederatedQueryGraph, buildSupergraphAPIQueryGraph } from "@apollo/
 import { mergeSubgraphs } from "./merging";
 import { validateGraphComposition } from "./validate";
 import { CompositionHint } from "./hints";
+import { computeSchemaSize, SchemaSizeLimits, validateSchemaSize } from "./schemaSize";
 
 export type CompositionResult = CompositionFailure | CompositionSuccess;
 
@@ -37,6 +38,7 @@ export interface CompositionSuccess {
 export interface CompositionOptions {
   sdlPrintOptions?: PrintOptions;
   allowedFieldTypeMergingSubtypingRules?: SubtypingRule[];
+  schemaSizeLimits?: SchemaSizeLimits;
 }
 
 function validateCompositionOptions(options: CompositionOptions) {
@@ -86,10 +88,20 @@ export function compose(subgraphs: Subgraphs, options: CompositionOptions = {}):
     return { errors: [err] };
   }
 
+  let sizeHints: CompositionHint[] = [];
+  if (options.schemaSizeLimits) {
+    const size = computeSchemaSize(supergraph.apiSchema(), supergraphSdl);
+    const sizeValidation = validateSchemaSize(size, options.schemaSizeLimits);
+    if (sizeValidation.errors.length > 0) {
+      return { errors: sizeValidation.errors };
+    }
+    sizeHints = sizeValidation.hints;
+  }
+
   return {
     schema: supergraph.schema,
     supergraphSdl,
-    hints: mergeResult.hints.concat(hints ?? []),
+    hints: mergeResult.hints.concat(hints ?? [], sizeHints),
   };
 }
 
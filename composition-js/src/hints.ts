@@ -1,4 +1,4 @@
-import { NamedSchemaElement, SubgraphASTNode } from "@apollo/federation-internals";
+import { Diagnostic, diagnosticLocations, NamedSchemaElement, SubgraphASTNode } from "@apollo/federation-internals";
 import { printLocation } from "graphql";
 
 export enum HintLevel {
@@ -212,6 +212,12 @@ const IMPLICITLY_UPGRADED_FEDERATION_VERSION = makeCodeDefinition({
     + ' In this case, the supergraph uses the federation version required by the directive.'
 });
 
+const SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED = makeCodeDefinition({
+  code: 'SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED',
+  level: HintLevel.WARN,
+  description: 'Indicates that the composed supergraph exceeds one of the soft schema size limits (number of types, number of fields or size of the supergraph SDL) configured for composition.',
+});
+
 export const HINTS = {
   INCONSISTENT_BUT_COMPATIBLE_FIELD_TYPE,
   INCONSISTENT_BUT_COMPATIBLE_ARGUMENT_TYPE,
@@ -242,6 +248,7 @@ export const HINTS = {
   DIRECTIVE_COMPOSITION_WARN,
   INCONSISTENT_RUNTIME_TYPES_FOR_SHAREABLE_RETURN,
   IMPLICITLY_UPGRADED_FEDERATION_VERSION,
+  SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED,
 }
 
 export class CompositionHint {
@@ -282,3 +289,17 @@ export function printHint(hint: CompositionHint): string {
 
   return output;
 }
+
+/**
+ * Converts a composition hint to a diagnostic (see `diagnosticsToJSON` and `diagnosticsToSARIF`): `WARN` hints are
+ * reported as warnings, and other hints as notes.
+ */
+export function diagnosticFromHint(hint: CompositionHint): Diagnostic {
+  return {
+    code: hint.definition.code,
+    severity: hint.definition.level.value === HintLevel.WARN ? 'warning' : 'note',
+    message: hint.message,
+    coordinate: hint.coordinate,
+    locations: diagnosticLocations(hint.nodes),
+  };
+}
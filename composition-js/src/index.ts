@@ -1,3 +1,7 @@
 export * from './compose';
 export * from './merging';
 export * from './validate';
+export * from './supergraphArtifact';
+export * from './supergraphChangelog';
+export * from './schemaSize';
+export * from './subgraphCheck';
@@ -0,0 +1,68 @@
+import { ERRORS, Schema, schemaStats } from "@apollo/federation-internals";
+import { GraphQLError } from "graphql";
+import { CompositionHint, HINTS } from "./hints";
+
+export type SchemaSizeThresholds = {
+  // The maximum number of types of the supergraph API schema (built-in types are not counted).
+  maxTypes?: number,
+  // The maximum number of fields (including input fields) of the supergraph API schema.
+  maxFields?: number,
+  // The maximum size, in bytes, of the printed supergraph SDL.
+  maxSdlBytes?: number,
+}
+
+/**
+ * Limits on the size of the composed supergraph. Exceeding a `soft` threshold raises a `SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED` hint,
+ * while exceeding a `hard` one fails composition with a `SCHEMA_SIZE_LIMIT_EXCEEDED` error.
+ */
+export type SchemaSizeLimits = {
+  soft?: SchemaSizeThresholds,
+  hard?: SchemaSizeThresholds,
+}
+
+export type SchemaSize = {
+  types: number,
+  fields: number,
+  sdlBytes: number,
+}
+
+export function computeSchemaSize(apiSchema: Schema, supergraphSdl: string): SchemaSize {
+  const { types, fields } = schemaStats(apiSchema);
+  return {
+    types,
+    fields,
+    sdlBytes: new TextEncoder().encode(supergraphSdl).length,
+  };
+}
+
+function exceededThresholds(size: SchemaSize, thresholds: SchemaSizeThresholds | undefined): string[] {
+  if (!thresholds) {
+    return [];
+  }
+  const exceeded: string[] = [];
+  if (thresholds.maxTypes !== undefined && size.types > thresholds.maxTypes) {
+    exceeded.push(`it has ${size.types} types (limit: ${thresholds.maxTypes})`);
+  }
+  if (thresholds.maxFields !== undefined && size.fields > thresholds.maxFields) {
+    exceeded.push(`it has ${size.fields} fields (limit: ${thresholds.maxFields})`);
+  }
+  if (thresholds.maxSdlBytes !== undefined && size.sdlBytes > thresholds.maxSdlBytes) {
+    exceeded.push(`its SDL is ${size.sdlBytes} bytes (limit: ${thresholds.maxSdlBytes})`);
+  }
+  return exceeded;
+}
+
+export function validateSchemaSize(
+  size: SchemaSize,
+  limits: SchemaSizeLimits,
+): { errors: GraphQLError[], hints: CompositionHint[] } {
+  const errors = exceededThresholds(size, limits.hard).map((exceeded) => ERRORS.SCHEMA_SIZE_LIMIT_EXCEEDED.err(
+    `The supergraph exceeds the maximum allowed size: ${exceeded}.`
+  ));
+  const hints = exceededThresholds(size, limits.soft).map((exceeded) => new CompositionHint(
+    HINTS.SCHEMA_SIZE_SOFT_LIMIT_EXCEEDED,
+    `The supergraph exceeds the recommended size: ${exceeded}.`,
+    undefined,
+  ));
+  return { errors, hints };
+}
@@ -0,0 +1,102 @@
+import {
+  buildSubgraph,
+  diffSchemas,
+  errorCauses,
+  SchemaChange,
+  ServiceDefinition,
+  Subgraph,
+  Subgraphs,
+  Supergraph,
+} from "@apollo/federation-internals";
+import { DocumentNode, GraphQLError, GraphQLSchema, validate } from "graphql";
+import { compose, CompositionOptions } from "./compose";
+import { CompositionHint } from "./hints";
+
+export interface SubgraphCheckOptions extends CompositionOptions {
+  // Operations (typically, the ones recently executed against the graph) to validate against the updated API schema.
+  operations?: DocumentNode[];
+}
+
+export type BrokenOperation = {
+  operation: DocumentNode,
+  errors: GraphQLError[],
+}
+
+export type SubgraphCheckResult = {
+  // The errors composing the new version of the subgraph with the other subgraphs of the supergraph. When there are any,
+  // the API schema changes and broken operations cannot be computed and are empty.
+  errors: GraphQLError[],
+  hints: CompositionHint[],
+  // The changes to the API schema resulting from the new version of the subgraph.
+  changes: SchemaChange[],
+  // The provided operations that are valid against the current API schema but not against the updated one.
+  brokenOperations: BrokenOperation[],
+  // The supergraph resulting from the update, if it composes.
+  supergraphSdl?: string,
+}
+
+/**
+ * Checks whether a new version of a subgraph (or a new subgraph) can be published to the provided supergraph: the
+ * subgraph replaces the one of the same name in the supergraph (if any) and the resulting subgraphs are composed.
+ *
+ * The check should be considered as failed if there are composition errors, breaking changes to the API schema (see
+ * `SchemaChange.severity`) or broken operations.
+ */
+export function checkSubgraph(
+  supergraph: Supergraph,
+  subgraph: ServiceDefinition,
+  options: SubgraphCheckOptions = {},
+): SubgraphCheckResult {
+  const emptyResult = { hints: [], changes: [], brokenOperations: [] };
+  const subgraphs = new Subgraphs();
+  for (const existing of supergraph.subgraphs().values()) {
+    if (existing.name !== subgraph.name) {
+      subgraphs.add(existing);
+    }
+  }
+  let updated: Subgraph;
+  try {
+    updated = buildSubgraph(subgraph.name, subgraph.url ?? '', subgraph.typeDefs);
+  } catch (e) {
+    const causes = errorCauses(e);
+    if (causes) {
+      return { errors: causes, ...emptyResult };
+    }
+    throw e;
+  }
+  subgraphs.add(updated);
+
+  const compositionResult = compose(subgraphs, options);
+  if (compositionResult.errors) {
+    return { errors: compositionResult.errors, ...emptyResult };
+  }
+
+  const currentAPISchema = supergraph.apiSchema();
+  const updatedAPISchema = compositionResult.schema.toAPISchema();
+  return {
+    errors: [],
+    hints: compositionResult.hints,
+    changes: diffSchemas(currentAPISchema, updatedAPISchema),
+    brokenOperations: brokenOperations(
+      options.operations ?? [],
+      currentAPISchema.toGraphQLJSSchema(),
+      updatedAPISchema.toGraphQLJSSchema(),
+    ),
+    supergraphSdl: compositionResult.supergraphSdl,
+  };
+}
+
+function brokenOperations(operations: DocumentNode[], currentSchema: GraphQLSchema, updatedSchema: GraphQLSchema): BrokenOperation[] {
+  const broken: BrokenOperation[] = [];
+  for (const operation of operations) {
+    // Operations that are already invalid are not broken by the update.
+    if (validate(currentSchema, operation).length > 0) {
+      continue;
+    }
+    const errors = validate(updatedSchema, operation);
+    if (errors.length > 0) {
+      broken.push({ operation, errors: errors.concat() });
+    }
+  }
+  return broken;
+}
@@ -0,0 +1,119 @@
+import { Subgraphs } from "@apollo/federation-internals";
+import { createHash } from "@apollo/utils.createhash";
+import { CompositionSuccess } from "./compose";
+
+/**
+ * The version of the artifact format written by `encodeSupergraphArtifact`. This should be bumped
+ * whenever the shape of `SupergraphArtifact` changes in a way that older readers cannot handle.
+ */
+export const SUPERGRAPH_ARTIFACT_FORMAT_VERSION = 1;
+
+export type SupergraphArtifactSubgraph = {
+  name: string,
+  url: string,
+  // The sha256 (hex-encoded) of the printed subgraph schema, as returned by `Subgraph.toString()`.
+  sdlHash: string,
+}
+
+export type SupergraphArtifactHint = {
+  code: string,
+  level: string,
+  message: string,
+  coordinate?: string,
+}
+
+/**
+ * A self-contained description of a composition: the supergraph SDL, the hints raised while composing it
+ * and the identity of the subgraphs it was composed from.
+ */
+export type SupergraphArtifact = {
+  formatVersion: number,
+  supergraphSdl: string,
+  // The sha256 (hex-encoded) of `supergraphSdl`.
+  supergraphHash: string,
+  subgraphs: SupergraphArtifactSubgraph[],
+  hints: SupergraphArtifactHint[],
+  metadata: Record<string, string>,
+}
+
+export function sha256Hex(content: string): string {
+  return createHash('sha256').update(content).digest('hex');
+}
+
+export function createSupergraphArtifact(
+  composition: CompositionSuccess,
+  subgraphs: Subgraphs,
+  metadata: Record<string, string> = {},
+): SupergraphArtifact {
+  return {
+    formatVersion: SUPERGRAPH_ARTIFACT_FORMAT_VERSION,
+    supergraphSdl: composition.supergraphSdl,
+    supergraphHash: sha256Hex(composition.supergraphSdl),
+    subgraphs: subgraphs.values().map((subgraph) => ({
+      name: subgraph.name,
+      url: subgraph.url,
+      sdlHash: sha256Hex(subgraph.toString()),
+    })),
+    hints: composition.hints.map((hint) => ({
+      code: hint.definition.code,
+      level: hint.definition.level.name,
+      message: hint.message,
+      // Not including `coordinate` when undefined so that artifacts are unchanged by a JSON round-trip.
+      ...(hint.coordinate ? { coordinate: hint.coordinate } : {}),
+    })),
+    metadata: { ...metadata },
+  };
+}
+
+/**
+ * The identifier of an artifact, which is the sha256 (hex-encoded) of its serialized payload. Two artifacts
+ * with the same content always have the same identifier, so this can be used to content-address artifacts.
+ */
+export function supergraphArtifactId(artifact: SupergraphArtifact): string {
+  return sha256Hex(JSON.stringify(artifact));
+}
+
+/**
+ * Encodes the provided artifact as a string that embeds a checksum of its content. See `supergraphArtifactFiles` for
+ * writing (gzip-compressed) artifacts to files in Node.js.
+ */
+export function encodeSupergraphArtifact(artifact: SupergraphArtifact): string {
+  const payload = JSON.stringify(artifact);
+  const envelope = {
+    checksum: sha256Hex(payload),
+    payload,
+  };
+  return JSON.stringify(envelope);
+}
+
+/**
+ * Decodes a string produced by `encodeSupergraphArtifact`, verifying its checksum.
+ *
+ * @throws if the string is not a valid artifact, if its checksum does not match its content, or if
+ *   it was written using an unsupported format version.
+ */
+export function decodeSupergraphArtifact(encoded: string): SupergraphArtifact {
+  let envelope: any;
+  try {
+    envelope = JSON.parse(encoded);
+  } catch (e) {
+    throw new Error(`Invalid supergraph artifact: ${e.message}`);
+  }
+
+  if (typeof envelope?.checksum !== 'string' || typeof envelope?.payload !== 'string') {
+    throw new Error('Invalid supergraph artifact: missing checksum or payload');
+  }
+  const actualChecksum = sha256Hex(envelope.payload);
+  if (actualChecksum !== envelope.checksum) {
+    throw new Error(`Invalid supergraph artifact: checksum mismatch (expected ${envelope.checksum} but got ${actualChecksum})`);
+  }
+
+  const artifact = JSON.parse(envelope.payload) as SupergraphArtifact;
+  if (artifact.formatVersion !== SUPERGRAPH_ARTIFACT_FORMAT_VERSION) {
+    throw new Error(`Unsupported supergraph artifact format version ${artifact.formatVersion} (supported version: ${SUPERGRAPH_ARTIFACT_FORMAT_VERSION})`);
+  }
+  if (sha256Hex(artifact.supergraphSdl) !== artifact.supergraphHash) {
+    throw new Error('Invalid supergraph artifact: the supergraph SDL does not match its recorded hash');
+  }
+  return artifact;
+}
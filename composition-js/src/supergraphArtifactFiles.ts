@@ -0,0 +1,37 @@
+/**
+ * Node.js-only helpers to store supergraph artifacts as gzip-compressed files.
+ *
+ * This module is not re-exported by the package index, so that using `@apollo/composition` does not require Node.js
+ * built-in modules. Import it as `@apollo/composition/dist/supergraphArtifactFiles`.
+ */
+import fs from "fs";
+import { gunzipSync, gzipSync } from "zlib";
+import { decodeSupergraphArtifact, encodeSupergraphArtifact, SupergraphArtifact } from "./supergraphArtifact";
+
+/**
+ * Serializes the provided artifact into a gzip-compressed buffer that embeds a checksum of its content.
+ */
+export function serializeSupergraphArtifact(artifact: SupergraphArtifact): Buffer {
+  return gzipSync(encodeSupergraphArtifact(artifact));
+}
+
+/**
+ * Deserializes a buffer produced by `serializeSupergraphArtifact`, verifying its checksum (see `decodeSupergraphArtifact`).
+ */
+export function deserializeSupergraphArtifact(data: Buffer): SupergraphArtifact {
+  let encoded: string;
+  try {
+    encoded = gunzipSync(data).toString('utf8');
+  } catch (e) {
+    throw new Error(`Invalid supergraph artifact: ${e.message}`);
+  }
+  return decodeSupergraphArtifact(encoded);
+}
+
+export function writeSupergraphArtifact(path: string, artifact: SupergraphArtifact) {
+  fs.writeFileSync(path, serializeSupergraphArtifact(artifact));
+}
+
+export function readSupergraphArtifact(path: string): SupergraphArtifact {
+  return deserializeSupergraphArtifact(fs.readFileSync(path));
+}
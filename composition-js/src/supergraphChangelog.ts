@@ -0,0 +1,124 @@
+import {
+  diffSchemas,
+  SchemaChange,
+  SchemaChangeSeverity,
+  Subgraphs,
+  Supergraph,
+} from "@apollo/federation-internals";
+import { SupergraphArtifact, supergraphArtifactId } from "./supergraphArtifact";
+
+export type SubgraphChangeKind = 'ADDED' | 'REMOVED' | 'UPDATED';
+
+export type SubgraphChange = {
+  subgraph: string,
+  kind: SubgraphChangeKind,
+  previousHash?: string,
+  newHash?: string,
+}
+
+export type SupergraphSchemaChange = SchemaChange & {
+  // The subgraphs, amongst the ones that changed between the 2 artifacts, that define the changed element (in either version).
+  subgraphs: string[],
+}
+
+export type SupergraphChangelog = {
+  from: string,
+  to: string,
+  subgraphChanges: SubgraphChange[],
+  schemaChanges: SupergraphSchemaChange[],
+}
+
+/**
+ * Computes the changes to the API schema between 2 supergraph artifacts (as classified by `diffSchemas`), attributing
+ * each change to the subgraphs whose schema changed and that define the modified element.
+ */
+export function supergraphArtifactChangelog(previous: SupergraphArtifact, next: SupergraphArtifact): SupergraphChangelog {
+  const subgraphChanges = computeSubgraphChanges(previous, next);
+
+  const previousSupergraph = Supergraph.build(previous.supergraphSdl);
+  const nextSupergraph = Supergraph.build(next.supergraphSdl);
+  const changedSubgraphs = subgraphChanges.map((c) => c.subgraph);
+  const attributor = (coordinate: string) => attributeChange(
+    coordinate,
+    changedSubgraphs,
+    previousSupergraph.subgraphs(),
+    nextSupergraph.subgraphs(),
+  );
+
+  const schemaChanges = diffSchemas(previousSupergraph.apiSchema(), nextSupergraph.apiSchema())
+    .map((change) => ({ ...change, subgraphs: attributor(change.coordinate) }));
+
+  return {
+    from: supergraphArtifactId(previous),
+    to: supergraphArtifactId(next),
+    subgraphChanges,
+    schemaChanges,
+  };
+}
+
+function computeSubgraphChanges(previous: SupergraphArtifact, next: SupergraphArtifact): SubgraphChange[] {
+  const previousHashes = new Map(previous.subgraphs.map((s) => [s.name, s.sdlHash]));
+  const nextHashes = new Map(next.subgraphs.map((s) => [s.name, s.sdlHash]));
+  const changes: SubgraphChange[] = [];
+  for (const [subgraph, previousHash] of previousHashes) {
+    const newHash = nextHashes.get(subgraph);
+    if (newHash === undefined) {
+      changes.push({ subgraph, kind: 'REMOVED', previousHash });
+    } else if (newHash !== previousHash) {
+      changes.push({ subgraph, kind: 'UPDATED', previousHash, newHash });
+    }
+  }
+  for (const [subgraph, newHash] of nextHashes) {
+    if (!previousHashes.has(subgraph)) {
+      changes.push({ subgraph, kind: 'ADDED', newHash });
+    }
+  }
+  // Subgraph names are compared by code units (not `localeCompare`) so that the order does not depend on the locale.
+  return changes.sort((c1, c2) => c1.subgraph === c2.subgraph ? 0 : c1.subgraph < c2.subgraph ? -1 : 1);
+}
+
+function attributeChange(
+  coordinate: string,
+  changedSubgraphs: string[],
+  previousSubgraphs: Subgraphs,
+  nextSubgraphs: Subgraphs,
+): string[] {
+  const definesElement = (subgraphs: Subgraphs, name: string) => !!subgraphs.get(name)?.schema.elementByCoordinate(coordinate);
+  return changedSubgraphs.filter((name) => definesElement(previousSubgraphs, name) || definesElement(nextSubgraphs, name));
+}
+
+export function supergraphChangelogToJSON(changelog: SupergraphChangelog): string {
+  return JSON.stringify(changelog, null, 2);
+}
+
+const severityLabels: Record<SchemaChangeSeverity, string> = {
+  BREAKING: 'Breaking',
+  DANGEROUS: 'Dangerous',
+  SAFE: 'Safe',
+};
+
+export function supergraphChangelogToMarkdown(changelog: SupergraphChangelog): string {
+  let output = '# Supergraph changelog\n\n';
+  output += `From \`${changelog.from}\` to \`${changelog.to}\`.\n`;
+
+  output += '\n## Subgraphs\n\n';
+  if (changelog.subgraphChanges.length === 0) {
+    output += 'No subgraph changed.\n';
+  }
+  for (const change of changelog.subgraphChanges) {
+    output += `- \`${change.subgraph}\`: ${change.kind.toLowerCase()}\n`;
+  }
+
+  output += '\n## Schema changes\n\n';
+  if (changelog.schemaChanges.length === 0) {
+    output += 'No change to the API schema.\n';
+  }
+  for (const change of changelog.schemaChanges) {
+    output += `- **${severityLabels[change.severity]}** \`${change.coordinate}\`: ${change.message}`;
+    if (change.subgraphs.length > 0) {
+      output += ` (from ${change.subgraphs.map((s) => `\`${s}\``).join(', ')})`;
+    }
+    output += '\n';
+  }
+  return output;
+}
@@ -0,0 +1,51 @@
+import {
+  normalizeOperationString,
+  persistedQueryHash,
+  PersistedQueryStore,
+} from '../persistedQueries';
+
+describe('persistedQueryHash', () => {
+  it('is the hex-encoded sha256 of the query', () => {
+    expect(persistedQueryHash('{__typename}')).toBe(
+      'ecf4edb46db40b5132295c0291d62fb65d6759a9eedfa4d5d612dd5ec54a6b38',
+    );
+  });
+});
+
+describe('normalizeOperationString', () => {
+  it('removes formatting differences', () => {
+    const query = `
+      # Fetches the current user
+      query Me {
+        me {
+          id,
+          name
+        }
+      }
+    `;
+    expect(normalizeOperationString(query)).toBe('query Me{me{id name}}');
+    expect(persistedQueryHash(normalizeOperationString(query))).toBe(
+      persistedQueryHash(normalizeOperationString('query Me { me { id name } }')),
+    );
+  });
+});
+
+describe('PersistedQueryStore', () => {
+  it('maps hashes to parsed documents', () => {
+    const store = new PersistedQueryStore();
+    const hash = store.add('{ me { id } }');
+    expect(hash).toBe(persistedQueryHash('{ me { id } }'));
+    expect(store.has(hash)).toBe(true);
+    expect(store.get(hash)?.definitions).toHaveLength(1);
+    expect(store.get(persistedQueryHash('{ me { name } }'))).toBeUndefined();
+    expect(store.size).toBe(1);
+  });
+
+  it('rejects queries not matching the expected hash', () => {
+    const store = new PersistedQueryStore();
+    expect(() => store.add('{ me { id } }', persistedQueryHash('{ me { name } }'))).toThrow(
+      'provided sha does not match query',
+    );
+    expect(store.size).toBe(0);
+  });
+});
@@ -1,6 +1,6 @@
 import { isObject } from '../utilities/predicates';
 import { GraphQLDataSource, GraphQLDataSourceProcessOptions, GraphQLDataSourceRequestKind } from './types';
-import { createHash } from '@apollo/utils.createhash';
+import { persistedQueryHash } from '../persistedQueries';
 import { ResponsePath } from '@apollo/query-planner';
 import { parseCacheControlHeader } from './parseCacheControlHeader';
 import fetcher from 'make-fetch-happen';
@@ -119,7 +119,7 @@ export class RemoteGraphQLDataSource<
         : null;
 
     if (this.apq) {
-      const apqHash = createHash('sha256').update(request.query).digest('hex');
+      const apqHash = persistedQueryHash(request.query);
 
       // Take the original extensions and extend them with
       // the necessary "extensions" for APQ handshaking.
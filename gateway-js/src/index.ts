@@ -1048,6 +1048,12 @@ export {
 
 export * from './datasources';
 
+export {
+  persistedQueryHash,
+  normalizeOperationString,
+  PersistedQueryStore,
+} from './persistedQueries';
+
 export {
   SupergraphSdlUpdateFunction,
   SubgraphHealthCheckFunction,
@@ -0,0 +1,62 @@
+import { createHash } from '@apollo/utils.createhash';
+import { DocumentNode, parse, print, stripIgnoredCharacters } from 'graphql';
+
+/**
+ * The hash identifying a query in Apollo's automatic persisted queries (APQ)
+ * protocol: the hex-encoded sha256 of the query string.
+ *
+ * Note that the hash is computed on the query string exactly as sent, so
+ * 2 queries only differing by formatting have different hashes (see
+ * `normalizeOperationString` for a way to avoid that when both ends agree on
+ * it).
+ */
+export function persistedQueryHash(query: string): string {
+  return createHash('sha256').update(query).digest('hex');
+}
+
+/**
+ * A canonical string for the provided operation: the printed document with
+ * comments and insignificant whitespace removed. Queries only differing by
+ * formatting or comments normalize to the same string (and so to the same
+ * `persistedQueryHash`).
+ */
+export function normalizeOperationString(query: string | DocumentNode): string {
+  return stripIgnoredCharacters(
+    print(typeof query === 'string' ? parse(query) : query),
+  );
+}
+
+/**
+ * A table mapping the hash of persisted queries to their parsed document.
+ */
+export class PersistedQueryStore {
+  private readonly documents = new Map<string, DocumentNode>();
+
+  /**
+   * Parses and stores the provided query, returning its hash. If the expected
+   * hash of the query is provided (as in an APQ registration request), it is
+   * checked against the query first.
+   */
+  add(query: string, expectedHash?: string): string {
+    const hash = persistedQueryHash(query);
+    if (expectedHash !== undefined && expectedHash !== hash) {
+      throw new Error('provided sha does not match query');
+    }
+    if (!this.documents.has(hash)) {
+      this.documents.set(hash, parse(query));
+    }
+    return hash;
+  }
+
+  get(hash: string): DocumentNode | undefined {
+    return this.documents.get(hash);
+  }
+
+  has(hash: string): boolean {
+    return this.documents.has(hash);
+  }
+
+  get size(): number {
+    return this.documents.size;
+  }
+}
@@ -8,6 +8,7 @@ import {
   SchemaElement,
   UnionType,
   InputObjectType,
+  ScalarType,
 } from '../definitions';
 import {
   printSchema as printGraphQLjsSchema,
@@ -44,6 +45,11 @@ function expectEnumType(type?: Type): asserts type is EnumType {
   expect(type!.kind).toBe('EnumType');
 }
 
+function expectScalarType(type?: Type): asserts type is ScalarType {
+  expect(type).toBeDefined();
+  expect(type!.kind).toBe('ScalarType');
+}
+
 declare global {
   namespace jest {
     interface Matchers<R> {
@@ -163,6 +169,59 @@ test('parse schema and modify', () => {
   expect(typeA).not.toHaveField('f1');
 });
 
+test('root types with non-default names', () => {
+  const sdl = `
+    schema {
+      query: MyQuery
+      mutation: MyMutation
+      subscription: MySubscription
+    }
+
+    type MyQuery {
+      a: Int
+    }
+
+    type MyMutation {
+      setA(a: Int): Int
+    }
+
+    type MySubscription {
+      onA: Int
+    }
+
+    type Query {
+      notARoot: Int
+    }`;
+  const schema = parseSchema(sdl);
+
+  const queryType = schema.type('MyQuery');
+  const mutationType = schema.type('MyMutation');
+  const subscriptionType = schema.type('MySubscription');
+  const notRootType = schema.type('Query');
+  expectObjectType(queryType);
+  expectObjectType(mutationType);
+  expectObjectType(subscriptionType);
+  expectObjectType(notRootType);
+
+  expect(queryType.isRootType()).toBe(true);
+  expect(queryType.isQueryRootType()).toBe(true);
+  expect(mutationType.isMutationRootType()).toBe(true);
+  expect(mutationType.isQueryRootType()).toBe(false);
+  expect(subscriptionType.isSubscriptionRootType()).toBe(true);
+  expect(notRootType.isRootType()).toBe(false);
+  expect(notRootType.isQueryRootType()).toBe(false);
+
+  // Introspection fields are added to the actual query root, whatever its name.
+  expect(queryType.field('__typename')).toBeDefined();
+  expect(queryType.field('__schema')).toBeDefined();
+  expect(notRootType.field('__schema')).toBeUndefined();
+
+  const apiSchema = schema.toAPISchema();
+  expect(apiSchema.schemaDefinition.root('query')?.type.name).toBe('MyQuery');
+  expect(apiSchema.schemaDefinition.root('mutation')?.type.name).toBe('MyMutation');
+  expect(apiSchema.schemaDefinition.root('subscription')?.type.name).toBe('MySubscription');
+});
+
 test('removal of all directives of a schema', () => {
   const subgraph = buildSubgraph('foo', '', `
     schema @foo {
@@ -686,6 +745,29 @@ test('reject type defined multiple times', () => {
   expect(() => buildSchema(sdl).validate()).toThrow('There can be only one type named "Foo"');
 });
 
+test('suggest known types for unknown type references', () => {
+  expect(() => buildSchema('type Query { a: Strng }')).toThrow('Unknown type Strng. Did you mean "String"?');
+  expect(() => buildSchema('type Query { a: Unrelated }')).toThrow(/Unknown type Unrelated$/m);
+});
+
+test('reject directive applied at a location it does not support', () => {
+  const sdl = `
+    directive @foo on FIELD_DEFINITION | ARGUMENT_DEFINITION
+    directive @bar on ENUM_VALUE
+
+    type Query @foo {
+      a: Int @bar
+    }
+  `;
+
+  expect(() => buildSchema(sdl)).toThrow(
+    'Directive "@foo" may not be used on OBJECT. It can only be used on FIELD_DEFINITION or ARGUMENT_DEFINITION.'
+  );
+  expect(() => buildSchema(sdl)).toThrow(
+    'Directive "@bar" may not be used on FIELD_DEFINITION. It can only be used on ENUM_VALUE.'
+  );
+});
+
 test('default arguments for directives', () => {
   const sdl = `
     directive @Example(inputObject: ExampleInputObject! = {}) on FIELD_DEFINITION
@@ -979,3 +1061,24 @@ test('parse error', () => {
 
   expect(subgraph.toString()).toMatchString(schema);
 });
+
+test('scalar specification URL', () => {
+  const schema = parseSchema(`
+    type Query {
+      a: UUID
+      b: Opaque
+    }
+
+    scalar UUID @specifiedBy(url: "https://tools.ietf.org/html/rfc4122")
+
+    scalar Opaque
+  `);
+
+  const uuid = schema.type('UUID');
+  const opaque = schema.type('Opaque');
+  expectScalarType(uuid);
+  expectScalarType(opaque);
+  expect(uuid.specifiedByURL()).toBe('https://tools.ietf.org/html/rfc4122');
+  expect(opaque.specifiedByURL()).toBeUndefined();
+  expect(schema.intType().specifiedByURL()).toBeUndefined();
+});
@@ -0,0 +1,80 @@
+import { parse } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import { deprecatedUsages, schemaDeprecations } from '../deprecations';
+import { sourceLocation } from '../parsing';
+
+const schema = buildSchema(`
+  type Query {
+    user(id: ID, login: String @deprecated(reason: "Use id")): User
+    users(filter: UserFilter): [User]
+  }
+
+  type User {
+    name: String
+    fullName: String @deprecated(reason: "Use name")
+    legacyId: ID @deprecated
+    status: Status
+  }
+
+  input UserFilter {
+    status: Status
+    active: Boolean @deprecated(reason: "Use status")
+  }
+
+  enum Status {
+    ACTIVE
+    DISABLED @deprecated(reason: "Use ACTIVE")
+  }
+`);
+
+describe('schemaDeprecations', () => {
+  it('lists deprecated elements with their reason', () => {
+    expect(schemaDeprecations(schema).map((d) => [d.coordinate, d.reason])).toStrictEqual([
+      ['Query.user(login:)', 'Use id'],
+      ['User.fullName', 'Use name'],
+      ['User.legacyId', 'No longer supported'],
+      ['UserFilter.active', 'Use status'],
+      ['Status.DISABLED', 'Use ACTIVE'],
+    ]);
+  });
+
+  it('positions deprecated elements', () => {
+    const [deprecation] = schemaDeprecations(buildSchema('type Query {\n  a: Int @deprecated\n}'));
+    expect(deprecation.nodes.map((n) => sourceLocation(n))).toStrictEqual(['GraphQL request:2:3']);
+  });
+});
+
+describe('deprecatedUsages', () => {
+  it('finds usages of deprecated elements in operations', () => {
+    const document = parse(`
+      query GetUser {
+        user(login: "bob") {
+          name
+          fullName
+        }
+      }
+
+      query ListUsers {
+        users(filter: { active: true, status: DISABLED }) {
+          ...UserFields
+        }
+      }
+
+      fragment UserFields on User {
+        legacyId
+        status
+      }
+    `);
+    expect(deprecatedUsages(schema, [document]).map((u) => [u.operationName, u.coordinate, u.reason])).toStrictEqual([
+      ['GetUser', 'Query.user(login:)', 'Use id'],
+      ['GetUser', 'User.fullName', 'Use name'],
+      ['ListUsers', 'UserFilter.active', 'Use status'],
+      ['ListUsers', 'Status.DISABLED', 'Use ACTIVE'],
+      [undefined, 'User.legacyId', 'No longer supported'],
+    ]);
+  });
+
+  it('returns nothing for operations not using deprecated elements', () => {
+    expect(deprecatedUsages(schema, [parse('{ user(id: 1) { name status } }')])).toStrictEqual([]);
+  });
+});
@@ -0,0 +1,125 @@
+import { GraphQLError, parse, Source } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import {
+  Diagnostic,
+  diagnosticsFromError,
+  diagnosticFromLintWarning,
+  diagnosticsToJSON,
+  diagnosticsToSARIF,
+} from '../diagnostics';
+import { ERRORS } from '../error';
+import { lintSchema, TYPE_NAMES_PASCAL_CASE } from '../lint';
+
+const source = new Source(`
+type Query {
+  me: user_info
+}
+
+type user_info {
+  id: ID!
+}
+`, 'schema.graphql');
+
+const schema = buildSchema(source);
+
+describe('diagnostics', () => {
+  it('converts errors with nodes', () => {
+    const type = schema.type('user_info')!;
+    const diagnostics = diagnosticsFromError(ERRORS.INVALID_GRAPHQL.err('Invalid type', { nodes: type.sourceAST }));
+    expect(diagnostics).toStrictEqual([{
+      code: 'INVALID_GRAPHQL',
+      severity: 'error',
+      message: 'Invalid type',
+      locations: [{ source: 'schema.graphql', subgraph: undefined, line: 6, column: 1 }],
+    }]);
+  });
+
+  it('converts syntax errors', () => {
+    let error: GraphQLError | undefined;
+    try {
+      parse(new Source('type {', 'broken.graphql'));
+    } catch (e) {
+      error = e as GraphQLError;
+    }
+    const diagnostics = diagnosticsFromError(error!);
+    expect(diagnostics.map((d) => d.code)).toStrictEqual(['GRAPHQL_ERROR']);
+    expect(diagnostics[0].locations).toStrictEqual([{ source: 'broken.graphql', line: 1, column: 6 }]);
+  });
+
+  it('converts each cause of aggregate errors', () => {
+    let error: GraphQLError | undefined;
+    try {
+      buildSchema(new Source('type Query {\n  a: Unknown1\n  b: Unknown2\n}\n', 'invalid.graphql'));
+    } catch (e) {
+      error = e as GraphQLError;
+    }
+    const diagnostics = diagnosticsFromError(error!);
+    expect(diagnostics.map((d) => [d.code, d.message, d.locations[0].line])).toStrictEqual([
+      ['INVALID_GRAPHQL', 'Unknown type Unknown1', 2],
+      ['INVALID_GRAPHQL', 'Unknown type Unknown2', 3],
+    ]);
+  });
+
+  it('converts lint warnings', () => {
+    const [warning] = lintSchema(schema, [TYPE_NAMES_PASCAL_CASE]);
+    const diagnostic = diagnosticFromLintWarning(warning);
+    expect(diagnostic.code).toBe('TYPE_NAMES_PASCAL_CASE');
+    expect(diagnostic.severity).toBe('warning');
+    expect(diagnostic.coordinate).toBe('user_info');
+    expect(diagnostic.locations.map((l) => [l.source, l.line])).toStrictEqual([['schema.graphql', 6]]);
+  });
+
+  const diagnostics: Diagnostic[] = [
+    {
+      code: 'INVALID_GRAPHQL',
+      severity: 'error',
+      message: 'Invalid type',
+      locations: [{ source: 'schema.graphql', line: 6, column: 1 }],
+    },
+    {
+      code: 'TYPE_NAMES_PASCAL_CASE',
+      severity: 'warning',
+      message: 'Type names should be in PascalCase',
+      coordinate: 'user_info',
+      locations: [],
+    },
+  ];
+
+  it('serializes to JSON', () => {
+    expect(JSON.parse(diagnosticsToJSON(diagnostics))).toStrictEqual(diagnostics);
+  });
+
+  it('serializes to SARIF', () => {
+    const sarif = JSON.parse(diagnosticsToSARIF(diagnostics, { toolName: 'schema-check', toolVersion: '1.0.0' }));
+    expect(sarif.version).toBe('2.1.0');
+    expect(sarif.runs).toHaveLength(1);
+    const run = sarif.runs[0];
+    expect(run.tool.driver).toStrictEqual({
+      name: 'schema-check',
+      version: '1.0.0',
+      rules: [{ id: 'INVALID_GRAPHQL' }, { id: 'TYPE_NAMES_PASCAL_CASE' }],
+    });
+    expect(run.results).toStrictEqual([
+      {
+        ruleId: 'INVALID_GRAPHQL',
+        ruleIndex: 0,
+        level: 'error',
+        message: { text: 'Invalid type' },
+        locations: [{
+          physicalLocation: {
+            artifactLocation: { uri: 'schema.graphql' },
+            region: { startLine: 6, startColumn: 1 },
+          },
+        }],
+      },
+      {
+        ruleId: 'TYPE_NAMES_PASCAL_CASE',
+        ruleIndex: 1,
+        level: 'warning',
+        message: { text: 'Type names should be in PascalCase' },
+        locations: [],
+        logicalLocations: [{ fullyQualifiedName: 'user_info' }],
+      },
+    ]);
+  });
+});
@@ -0,0 +1,103 @@
+import { buildSchema } from '../buildSchema';
+import { buildSubgraph } from '../federation';
+import { LintRule, lintSchema, NO_BOOLEAN_FIELD_PREFIXES } from '../lint';
+import { sourceLocation } from '../parsing';
+
+function warnings(sdl: string, rules?: LintRule[]): [string, string][] {
+  return lintSchema(buildSchema(sdl), rules).map((w) => [w.rule, w.coordinate]);
+}
+
+describe('lintSchema', () => {
+  it('returns no warning for a conforming schema', () => {
+    expect(warnings(`
+      type Query {
+        "The current user"
+        me: User
+      }
+
+      type User {
+        "Whether the user is an administrator"
+        admin: Boolean!
+        "The status of the user"
+        status: UserStatus
+      }
+
+      enum UserStatus {
+        ACTIVE
+        ON_HOLD
+      }
+    `)).toStrictEqual([]);
+  });
+
+  it('reports violations of the default rules', () => {
+    expect(warnings(`
+      type Query {
+        "The current user"
+        me: user_info
+      }
+
+      type user_info {
+        "Whether the user is an administrator"
+        isAdmin: Boolean!
+        hasName: String
+        status: UserStatus
+      }
+
+      enum UserStatus {
+        ACTIVE
+        onHold
+      }
+    `)).toStrictEqual([
+      ['TYPE_NAMES_PASCAL_CASE', 'user_info'],
+      ['FIELDS_HAVE_DESCRIPTIONS', 'user_info.hasName'],
+      ['FIELDS_HAVE_DESCRIPTIONS', 'user_info.status'],
+      ['ENUM_VALUES_SCREAMING_CASE', 'UserStatus.onHold'],
+      ['NO_BOOLEAN_FIELD_PREFIXES', 'user_info.isAdmin'],
+    ]);
+  });
+
+  it('ignores the types and fields added to subgraphs by federation', () => {
+    const subgraph = buildSubgraph('users', 'http://users', `
+      extend schema @link(url: "https://specs.apollo.dev/federation/v2.0", import: ["@key"])
+
+      type Query {
+        "The current user"
+        me: User
+      }
+
+      type User @key(fields: "id") {
+        "The id of the user"
+        id: ID!
+      }
+    `);
+    expect(subgraph.schema.elementByCoordinate('Query._entities')).toBeDefined();
+    expect(lintSchema(subgraph.schema)).toStrictEqual([]);
+  });
+
+  it('positions warnings', () => {
+    const [warning] = lintSchema(buildSchema('type Query {\n  "A"\n  isA: Boolean\n}'), [NO_BOOLEAN_FIELD_PREFIXES]);
+    expect(warning.nodes.map((n) => sourceLocation(n))).toStrictEqual(['GraphQL request:2:3']);
+  });
+
+  it('supports custom rules', () => {
+    const noIdArguments: LintRule = {
+      name: 'NO_ID_ARGUMENTS',
+      argument: (arg, report) => {
+        if (arg.name === 'id') {
+          report(arg, `Argument "${arg.coordinate}" should not be named "id".`);
+        }
+      },
+    };
+    const result = lintSchema(buildSchema(`
+      type Query {
+        user(id: ID!): String
+      }
+
+      directive @cached(id: String) on FIELD_DEFINITION
+    `), [noIdArguments]);
+    expect(result.map((w) => w.message)).toStrictEqual([
+      'Argument "Query.user(id:)" should not be named "id".',
+      'Argument "@cached(id:)" should not be named "id".',
+    ]);
+  });
+});
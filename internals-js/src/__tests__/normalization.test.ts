@@ -0,0 +1,152 @@
+import { parse, print } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import { normalizeDocument, schemaHash, semanticallyEqual } from '../normalization';
+
+describe('semanticallyEqual', () => {
+  const sdl = `
+    """
+    A user
+    of the service
+    """
+    type User {
+      id: ID!
+      name: String
+    }
+
+    type Query {
+      me: User
+    }
+  `;
+
+  it('ignores locations, definitions order and description whitespace', () => {
+    const other = `
+      type Query { me: User }
+
+      "A user of the service"
+      type User { id: ID! name: String }
+    `;
+    expect(semanticallyEqual(parse(sdl), parse(other))).toBe(true);
+    expect(semanticallyEqual(parse(sdl), parse(other, { noLocation: true }))).toBe(true);
+  });
+
+  it('accepts schemas', () => {
+    expect(semanticallyEqual(buildSchema(sdl), parse(sdl))).toBe(true);
+  });
+
+  it('detects differences', () => {
+    expect(semanticallyEqual(parse(sdl), parse(sdl.replace('name: String', 'name: String!')))).toBe(false);
+    expect(semanticallyEqual(parse(sdl), parse(sdl.replace('A user', 'An user')))).toBe(false);
+    // The order of fields matters.
+    expect(semanticallyEqual(parse(sdl), parse(sdl.replace('id: ID!\n      name: String', 'name: String\n      id: ID!')))).toBe(false);
+  });
+});
+
+describe('normalizeDocument', () => {
+  it('sorts definitions and their elements', () => {
+    const normalized = normalizeDocument(parse(`
+      type Query {
+        users(limit: Int, after: ID): [User]
+      }
+
+      directive @custom(b: Int, a: Int) on OBJECT | FIELD_DEFINITION
+
+      """
+        A user
+      """
+      type User implements Node & Actor {
+        name: String
+        id: ID!
+      }
+
+      union Result = User | Bot
+
+      enum Status {
+        INACTIVE
+        ACTIVE
+      }
+
+      input Filter {
+        status: Status
+        after: ID
+      }
+    `));
+    expect(print(normalized)).toMatchString(`
+      directive @custom(a: Int, b: Int) on FIELD_DEFINITION | OBJECT
+
+      input Filter {
+        after: ID
+        status: Status
+      }
+
+      type Query {
+        users(after: ID, limit: Int): [User]
+      }
+
+      union Result = Bot | User
+
+      enum Status {
+        ACTIVE
+        INACTIVE
+      }
+
+      "A user"
+      type User implements Actor & Node {
+        id: ID!
+        name: String
+      }
+    `);
+    expect(normalized.loc).toBeUndefined();
+    expect(normalized.definitions[0].loc).toBeUndefined();
+  });
+
+  it('sorts names by code units, independently of the locale', () => {
+    const normalized = normalizeDocument(parse(`
+      enum E {
+        b
+        B
+        a
+        A
+        _a
+      }
+    `));
+    expect(print(normalized)).toMatchString(`
+      enum E {
+        A
+        B
+        _a
+        a
+        b
+      }
+    `);
+  });
+
+  it('normalizes equivalent schemas to the same document', () => {
+    const s1 = buildSchema(`
+      type Query { b: Int, a(y: Int, x: Int): String }
+    `);
+    const s2 = buildSchema(`
+      type Query { a(x: Int, y: Int): String, b: Int }
+    `);
+    expect(semanticallyEqual(s1, s2)).toBe(false);
+    expect(print(normalizeDocument(s1))).toBe(print(normalizeDocument(s2)));
+  });
+});
+
+describe('schemaHash', () => {
+  it('is stable across equivalent schemas', () => {
+    const hash = schemaHash(buildSchema(`
+      type Query { b: Int, a(y: Int, x: Int): String }
+    `));
+    expect(hash).toMatch(/^[0-9a-f]{64}$/);
+    expect(schemaHash(parse(`
+      type Query {
+        a(x: Int, y: Int): String
+        b: Int
+      }
+    `))).toBe(hash);
+  });
+
+  it('changes when the schema changes', () => {
+    expect(schemaHash(parse('type Query { a: Int }'))).not.toBe(schemaHash(parse('type Query { a: Int! }')));
+  });
+});
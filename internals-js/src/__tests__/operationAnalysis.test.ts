@@ -0,0 +1,131 @@
+import { parse } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import { analyzeVariables, operationComplexity, operationDepth } from '../operationAnalysis';
+import { parseOperation } from '../operations';
+
+const schema = buildSchema(`
+  type Query {
+    me: User
+    users(first: Int): [User!]!
+    search(text: String!): [Result]
+  }
+
+  type User {
+    id: ID!
+    name: String
+    friends(limit: Int): [User]
+  }
+
+  type Post {
+    title: String
+  }
+
+  union Result = User | Post
+`);
+
+describe('operationDepth', () => {
+  it('counts nested fields', () => {
+    expect(operationDepth(parseOperation(schema, '{ me { id } }'))).toBe(2);
+    expect(operationDepth(parseOperation(schema, '{ me { id friends { friends { name } } } users { id } }'))).toBe(4);
+  });
+
+  it('ignores fragments', () => {
+    expect(operationDepth(parseOperation(schema, `
+      {
+        search(text: "a") {
+          ... on User {
+            ...UserFields
+          }
+        }
+      }
+
+      fragment UserFields on User {
+        friends {
+          name
+        }
+      }
+    `))).toBe(3);
+  });
+});
+
+describe('operationComplexity', () => {
+  it('uses field costs and list sizes', () => {
+    const operation = parseOperation(schema, `
+      query ($n: Int = 5) {
+        me { name }
+        users(first: 10) { id friends(limit: $n) { name } }
+      }
+    `);
+    // me: 1 + name: 1 = 2
+    // users: 1 + 10 * (id: 1 + friends: 1 + 5 * name: 1) = 71
+    expect(operationComplexity(operation)).toBe(73);
+    // With $n = 2: users: 1 + 10 * (1 + 1 + 2) = 41
+    expect(operationComplexity(operation, { variables: { n: 2 } })).toBe(43);
+    expect(operationComplexity(operation, { fieldCosts: { 'Query.users': 100 } })).toBe(172);
+  });
+
+  it('uses the default list size for lists without size argument', () => {
+    const operation = parseOperation(schema, '{ search(text: "a") { __typename ... on Post { title } } }');
+    expect(operationComplexity(operation)).toBe(2);
+    expect(operationComplexity(operation, { defaultListSize: 20 })).toBe(21);
+  });
+
+  it('ignores list sizes that are not non-negative integers', () => {
+    const operation = parseOperation(schema, 'query ($n: Int) { users(first: -1000) { id friends(limit: $n) { name } } }');
+    // users: 1 + 1 * (id: 1 + friends: 1 + 1 * name: 1) = 4
+    expect(operationComplexity(operation)).toBe(4);
+    expect(operationComplexity(operation, { variables: { n: -5 } })).toBe(4);
+    expect(operationComplexity(operation, { variables: { n: 2.5 }, defaultListSize: 3 })).toBe(16);
+  });
+});
+
+describe('analyzeVariables', () => {
+  it('lists variables with their usages', () => {
+    const analysis = analyzeVariables(schema, parse(`
+      query ($n: Int, $text: String! = "a", $unused: ID) {
+        users(first: $n) {
+          ...Friends
+        }
+        search(text: $text) {
+          __typename
+        }
+      }
+
+      fragment Friends on User {
+        friends(limit: $n) {
+          id
+        }
+      }
+    `));
+    expect(analysis.variables.map((v) => [v.name, v.type, v.usages.map((u) => u.expectedType)])).toStrictEqual([
+      ['n', 'Int', ['Int', 'Int']],
+      ['text', 'String!', ['String!']],
+      ['unused', 'ID', []],
+    ]);
+    expect(analysis.unused).toStrictEqual(['unused']);
+    expect(analysis.undefinedUsages).toStrictEqual([]);
+    expect(analysis.errors).toStrictEqual([]);
+  });
+
+  it('detects undefined variables', () => {
+    const analysis = analyzeVariables(schema, parse('{ users(first: $n) { id } }'));
+    expect(analysis.undefinedUsages.map((u) => [u.name, u.expectedType])).toStrictEqual([['n', 'Int']]);
+  });
+
+  it('detects invalid usages and default values', () => {
+    const analysis = analyzeVariables(schema, parse(`
+      query ($text: String, $n: Int = "ten") {
+        search(text: $text) {
+          __typename
+        }
+        users(first: $n) {
+          id
+        }
+      }
+    `));
+    expect(analysis.errors.map((e) => e.message)).toStrictEqual([
+      'Int cannot represent non-integer value: "ten"',
+      'Variable "$text" of type "String" used in position expecting type "String!".',
+    ]);
+  });
+});
@@ -0,0 +1,69 @@
+import { parse } from 'graphql';
+import { operationSignature } from '../operationSignature';
+
+describe('operationSignature', () => {
+  it('hides literals, removes aliases and sorts the operation', () => {
+    const document = parse(`
+      query Foo($b: Int, $a: Boolean) {
+        user(name: "hello", age: 5) {
+          ...Bar
+          ... on User {
+            hello
+            bee
+          }
+          tz
+          aliased: name
+        }
+      }
+
+      fragment Bar on User {
+        age @skip(if: $a)
+        ...Nested
+      }
+
+      fragment Nested on User {
+        blah
+      }
+    `);
+    expect(operationSignature(document)).toBe(
+      'fragment Bar on User{age@skip(if:$a)...Nested}'
+      + 'fragment Nested on User{blah}'
+      + 'query Foo($a:Boolean,$b:Int){user(age:0,name:""){name tz...Bar...on User{bee hello}}}'
+    );
+  });
+
+  it('hides input values but keeps enums, booleans, nulls and variables', () => {
+    const document = parse(`
+      query {
+        search(filter: { text: "secret", tags: ["a", "b"] }, order: DESC, exact: true, after: null, first: $first, score: 1.5)
+      }
+    `);
+    expect(operationSignature(document)).toBe('{search(after:null,exact:true,filter:{},first:$first,order:DESC,score:0)}');
+  });
+
+  it('gives the same signature to operations only differing by formatting, order or literals', () => {
+    const s1 = operationSignature(parse('query Q { a(x: 1) { b c } d }'));
+    const s2 = operationSignature(parse(`
+      # A comment
+      query Q {
+        d
+        a(x: 2) {
+          c
+          other: b
+        }
+      }
+    `));
+    expect(s1).toBe(s2);
+  });
+
+  it('only includes the selected operation and the fragments it uses', () => {
+    const document = parse(`
+      query A { ...F }
+      query B { b }
+      fragment F on Query { a }
+      fragment G on Query { g }
+    `);
+    expect(operationSignature(document, 'B')).toBe('query B{b}');
+    expect(operationSignature(document, 'A')).toBe('fragment F on Query{a}query A{...F}');
+  });
+});
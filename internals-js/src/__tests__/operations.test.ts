@@ -6,8 +6,8 @@ import {
 } from '../definitions';
 import { buildSchema } from '../buildSchema';
 import { FederationBlueprint } from '../federation';
-import { FragmentRestrictionAtType, MutableSelectionSet, NamedFragmentDefinition, Operation, operationFromDocument, parseOperation } from '../operations';
-import { DocumentNode, FieldNode, GraphQLError, Kind, OperationDefinitionNode, OperationTypeNode, parse, SelectionNode, SelectionSetNode, validate } from 'graphql';
+import { extractOperationDocument, FragmentRestrictionAtType, MutableSelectionSet, NamedFragmentDefinition, Operation, operationFromDocument, parseOperation } from '../operations';
+import { DocumentNode, FieldNode, GraphQLError, Kind, OperationDefinitionNode, OperationTypeNode, parse, print, SelectionNode, SelectionSetNode, validate } from 'graphql';
 import { assert } from '../utils';
 import gql from 'graphql-tag';
 
@@ -2473,6 +2473,45 @@ describe('validations', () => {
       }
     `);
   });
+
+  describe('suggestions', () => {
+    const schema = parseSchema(`
+      type Query {
+        t: T
+      }
+
+      type T {
+        name: String
+        names: [String]
+        id: ID!
+      }
+    `);
+
+    test('suggests fields for unknown fields', () => {
+      expect(() => parseOperation(schema, '{ t { nme } }')).toThrowError(new GraphQLError(
+        'Cannot query field "nme" on type "T". Did you mean "name" or "names"?'
+      ));
+      expect(() => parseOperation(schema, '{ t { somethingElse } }')).toThrowError(new GraphQLError(
+        'Cannot query field "somethingElse" on type "T".'
+      ));
+    });
+
+    test('suggests types for unknown fragment type conditions', () => {
+      expect(() => parseOperation(schema, '{ t { ...F } } fragment F on Tt { id }')).toThrowError(new GraphQLError(
+        'Unknown type "Tt" for fragment "F". Did you mean "T"?'
+      ));
+      expect(() => parseOperation(schema, '{ t { ... on Tt { id } } }')).toThrowError(new GraphQLError(
+        'Unknown type "Tt" for inline fragment. Did you mean "T"?'
+      ));
+      // Without suggestion, messages are unchanged.
+      expect(() => parseOperation(schema, '{ t { ...F } } fragment F on Unrelated { id }')).toThrowError(new GraphQLError(
+        'Unknown type "Unrelated" for fragment "F"'
+      ));
+      expect(() => parseOperation(schema, '{ t { ... on Unrelated { id } } }')).toThrowError(new GraphQLError(
+        'Unknown type "Unrelated" for inline fragment'
+      ));
+    });
+  });
 });
 
 describe('empty branches removal', () => {
@@ -3825,3 +3864,196 @@ describe('named fragment rebasing on subgraphs', () => {
     `);
   });
 });
+
+describe('extractOperationDocument', () => {
+  const document = parse(`
+    query A {
+      me {
+        ...UserFields
+      }
+    }
+
+    fragment Unused on User {
+      id
+    }
+
+    query B {
+      me {
+        id
+      }
+    }
+
+    fragment UserFields on User {
+      ...NameFields
+    }
+
+    fragment NameFields on User {
+      name
+    }
+  `);
+
+  it('extracts the named operation with the fragments it uses', () => {
+    expect(print(extractOperationDocument(document, 'A'))).toMatchString(`
+      query A {
+        me {
+          ...UserFields
+        }
+      }
+
+      fragment UserFields on User {
+        ...NameFields
+      }
+
+      fragment NameFields on User {
+        name
+      }
+    `);
+    expect(print(extractOperationDocument(document, 'B'))).toMatchString(`
+      query B {
+        me {
+          id
+        }
+      }
+    `);
+  });
+
+  it('extracts the only operation if no name is provided', () => {
+    const single = parse('{ me { ...F } } fragment F on User { id }');
+    expect(extractOperationDocument(single).definitions).toHaveLength(2);
+  });
+
+  it('errors on ambiguous or unknown operations', () => {
+    expect(() => extractOperationDocument(document)).toThrow('Must provide operation name if query contains multiple operations.');
+    expect(() => extractOperationDocument(document, 'C')).toThrow('Unknown operation named "C"');
+    expect(() => extractOperationDocument(parse('fragment F on User { id }'))).toThrow('No operation found in provided document.');
+  });
+});
+
+describe('withEvaluatedConditions', () => {
+  const schema = parseSchema(`
+    type Query {
+      t: T
+      u: Int
+    }
+
+    type T {
+      a: Int
+      b: Int
+      c: C
+    }
+
+    type C {
+      x: String
+      y: String
+    }
+  `);
+
+  const evaluated = (op: string, values?: Record<string, any>) => parseOperation(schema, op).withEvaluatedConditions(values).selectionSet.toString();
+
+  it('removes excluded selections and the conditions of included ones', () => {
+    const op = `
+      query ($s: Boolean!, $i: Boolean = true) {
+        t {
+          a @skip(if: $s)
+          b @include(if: $i)
+          c @include(if: false) {
+            x
+          }
+        }
+        u @skip(if: true)
+      }
+    `;
+    expect(evaluated(op, { s: true })).toBe('{ t { b } }');
+    expect(evaluated(op, { s: false, i: false })).toBe('{ t { a } }');
+  });
+
+  it('evaluates conditions on fragments', () => {
+    const op = `
+      query ($v: Boolean!) {
+        t {
+          ... @include(if: $v) {
+            a
+          }
+          ...F
+        }
+      }
+
+      fragment F on T {
+        b @skip(if: $v)
+        c {
+          x
+        }
+      }
+    `;
+    expect(evaluated(op, { v: true })).toBe('{ t { a c { x } } }');
+    expect(evaluated(op, { v: false })).toBe('{ t { b c { x } } }');
+  });
+
+  it('keeps fields whose sub-selections are all excluded', () => {
+    const op = `
+      query ($v: Boolean!) {
+        t {
+          a @skip(if: $v)
+        }
+        u
+      }
+    `;
+    expect(evaluated(op, { v: true })).toBe('{ t { __typename @include(if: false) } u }');
+  });
+
+  it('errors on missing variable values', () => {
+    const op = `
+      query ($v: Boolean!) {
+        t {
+          a @skip(if: $v)
+        }
+      }
+    `;
+    expect(() => evaluated(op)).toThrow('Missing or non-boolean value for variable "$v" used in @skip');
+  });
+
+  it('removes the variables that are no longer used', () => {
+    const op = `
+      query ($s: Boolean!, $i: Boolean = true, $v: Boolean!) {
+        t {
+          a @skip(if: $s)
+          c @include(if: $i) {
+            x @include(if: $v)
+            y @skip(if: $v)
+          }
+        }
+        u @include(if: $v)
+      }
+    `;
+    const operation = parseOperation(schema, op).withEvaluatedConditions({ s: false, v: true });
+    expect(operation.selectionSet.toString()).toBe('{ t { a c { x } } u }');
+    expect(operation.variableDefinitions.definitions().map((d) => d.variable.name)).toStrictEqual([]);
+    expect(validate(schema.toGraphQLJSSchema(), parse(operation.toString()))).toStrictEqual([]);
+  });
+
+  it('keeps the variables used in non-condition arguments', () => {
+    const schemaWithArgs = parseSchema(`
+      type Query {
+        t(id: ID!): T
+      }
+
+      type T {
+        a: Int
+        b: Int
+      }
+    `);
+    const op = `
+      query ($id: ID!, $v: Boolean!) {
+        t(id: $id) {
+          a @skip(if: $v)
+        }
+      }
+    `;
+    const operation = parseOperation(schemaWithArgs, op).withEvaluatedConditions({ v: true });
+    expect(operation.variableDefinitions.definitions().map((d) => d.variable.name)).toStrictEqual(['id']);
+    // The field whose sub-selections are all excluded is kept with a `__typename @include(if: false)` placeholder, which
+    // still makes a valid operation.
+    expect(operation.selectionSet.toString()).toBe('{ t(id: $id) { __typename @include(if: false) } }');
+    expect(validate(schemaWithArgs.toGraphQLJSSchema(), parse(operation.toString()))).toStrictEqual([]);
+  });
+});
@@ -0,0 +1,149 @@
+import { buildSchema, buildSchemaFromAST } from '../buildSchema';
+import { parseOperation } from '../operations';
+import { nestingDepth, nodeAtPosition, parseSources, parseWithLimits, sourceLocation } from '../parsing';
+import { Kind, NameNode, parse, Source } from 'graphql';
+
+const schema = buildSchema(`
+  type Query {
+    t: T
+  }
+
+  type T {
+    a: Int
+    t: T
+    l(v: [[Int]]): Int
+  }
+`);
+
+describe('parseWithLimits', () => {
+  it('parses documents within the limits', () => {
+    const document = parseWithLimits('{ t { a } }', { maxBytes: 100, maxTokens: 100, maxDepth: 2 });
+    expect(document.definitions).toHaveLength(1);
+  });
+
+  it('rejects documents exceeding the maximum size', () => {
+    expect(() => parseWithLimits('{ t { a } }', { maxBytes: 10 })).toThrow(
+      'Document is 11 bytes, which exceeds the maximum of 10 bytes.'
+    );
+    // The size is computed on the UTF-8 encoding of the document.
+    expect(() => parseWithLimits('# é\n{ t }', { maxBytes: 9 })).toThrow(
+      'Document is 10 bytes, which exceeds the maximum of 9 bytes.'
+    );
+  });
+
+  it('rejects documents exceeding the maximum number of tokens', () => {
+    expect(() => parseWithLimits('{ t { a } }', { maxTokens: 5 })).toThrow(
+      'Document contains more than 5 tokens. Parsing aborted.'
+    );
+  });
+
+  it('rejects documents exceeding the maximum depth', () => {
+    expect(() => parseWithLimits('{ t { t { t { a } } } }', { maxDepth: 3 })).toThrow(
+      'Document has a nesting depth of 4, which exceeds the maximum of 3.'
+    );
+  });
+
+  it('rejects pathologically nested documents before parsing them', () => {
+    const nesting = 100000;
+    const deepOperation = '{ t '.repeat(nesting) + '{ a }' + ' }'.repeat(nesting);
+    expect(() => parseWithLimits(deepOperation, { maxDepth: 10 })).toThrow(
+      'Document has a nesting depth exceeding the maximum of 10.'
+    );
+    const deepValue = '{ l(v: ' + '['.repeat(nesting) + ']'.repeat(nesting) + ') }';
+    expect(() => parseWithLimits(deepValue, { maxDepth: 10 })).toThrow(
+      'Document has a nesting depth exceeding the maximum of 10.'
+    );
+  });
+
+  it('accepts documents at the maximum depth with arguments and type bodies', () => {
+    expect(nestingDepth(parseWithLimits('{ l(v: [[1]]) }', { maxDepth: 3 }))).toBe(3);
+    expect(nestingDepth(parseWithLimits('type T { f(a: [[Int]]): Int }', { maxDepth: 2 }))).toBe(2);
+    expect(nestingDepth(parseWithLimits('type T { f(a: Int @d(x: [1])): Int }', { maxDepth: 1 }))).toBe(1);
+  });
+
+  it('applies limits when parsing operations', () => {
+    expect(() => parseOperation(schema, '{ t { t { a } } }', { parserOptions: { maxDepth: 2 } })).toThrow(
+      'Document has a nesting depth of 3, which exceeds the maximum of 2.'
+    );
+    expect(parseOperation(schema, '{ t { t { a } } }', { parserOptions: { maxDepth: 3 } }).selectionSet.selections()).toHaveLength(1);
+  });
+
+  it('applies limits when building schemas', () => {
+    const sdl = 'type Query { a: [[Int]] }';
+    expect(() => buildSchema(sdl, { parserOptions: { maxDepth: 1 } })).toThrow(
+      'Document has a nesting depth of 2, which exceeds the maximum of 1.'
+    );
+    expect(buildSchema(sdl, { parserOptions: { maxDepth: 2 } }).type('Query')).toBeDefined();
+  });
+});
+
+describe('empty braces', () => {
+  it('reports a targeted error on empty braces', () => {
+    const message = 'Syntax Error: Braces cannot be empty: either remove them or add at least one field (or enum value) inside them.';
+    expect(() => buildSchema('type Query { a: Int }\n\ntype Foo {}')).toThrow(message);
+    expect(() => buildSchema('type Query { a: Int }\n\nenum E {\n}')).toThrow(message);
+    expect(() => parseOperation(schema, '{ t {} }')).toThrow(message);
+  });
+
+  it('keeps the location of the error', () => {
+    try {
+      parseWithLimits('type Query {}');
+      throw new Error('should have thrown');
+    } catch (e) {
+      expect(e.locations).toStrictEqual([{ line: 1, column: 13 }]);
+    }
+  });
+
+  it('does not change other syntax errors', () => {
+    expect(() => parseWithLimits('type Query { : Int }')).toThrow('Syntax Error: Expected Name, found ":".');
+  });
+});
+
+describe('nestingDepth', () => {
+  it('counts selection sets, list and object values and list types', () => {
+    expect(nestingDepth(parse('{ t { a } }'))).toBe(2);
+    expect(nestingDepth(parse('{ l(v: [[1]]) }'))).toBe(3);
+    expect(nestingDepth(parse('query ($v: [[Int]]) { l(v: $v) }'))).toBe(2);
+    expect(nestingDepth(parse('type Query { a: Int }'))).toBe(0);
+  });
+});
+
+describe('parseSources', () => {
+  const accounts = new Source('type Query {\n  me: User\n}\n\ntype User {\n  id: ID!\n  email: String\n}\n', 'accounts.graphql');
+  const reviews = new Source('type Review {\n  body: String\n  author: Usr\n}\n', 'reviews.graphql');
+
+  it('tracks the source of each definition', () => {
+    const schema = buildSchemaFromAST(parseSources([accounts, new Source('type Review {\n  body: String\n}\n', 'reviews.graphql')]));
+    expect(sourceLocation(schema.elementByCoordinate('User.email')!.sourceAST!)).toBe('accounts.graphql:7:3');
+    expect(sourceLocation(schema.elementByCoordinate('Review')!.sourceAST!)).toBe('reviews.graphql:1:1');
+  });
+
+  it('reports errors in the file they occur in', () => {
+    expect(() => buildSchemaFromAST(parseSources([accounts, reviews]))).toThrow('reviews.graphql:3:11');
+  });
+
+  it('has no location for nodes without one', () => {
+    expect(sourceLocation(parse('{ a }', { noLocation: true }))).toBeUndefined();
+  });
+});
+
+describe('nodeAtPosition', () => {
+  const document = parse('type Query {\n  user(id: ID): User\n}\n\ntype User {\n  name: String\n}\n');
+
+  it('returns the most specific node at a line and column', () => {
+    const node = nodeAtPosition(document, { line: 2, column: 12 });
+    expect(node?.kind).toBe(Kind.NAME);
+    expect((node as NameNode).value).toBe('ID');
+    expect((nodeAtPosition(document, { line: 6, column: 4 }) as NameNode).value).toBe('name');
+    expect(nodeAtPosition(document, { line: 2, column: 15 })?.kind).toBe(Kind.FIELD_DEFINITION);
+  });
+
+  it('accepts offsets', () => {
+    expect((nodeAtPosition(document, document.loc!.source.body.indexOf('User {')) as NameNode).value).toBe('User');
+  });
+
+  it('returns the enclosing node between definitions', () => {
+    expect(nodeAtPosition(document, { line: 4, column: 1 })?.kind).toBe(Kind.DOCUMENT);
+    expect(nodeAtPosition(document, 1000)).toBeUndefined();
+  });
+});
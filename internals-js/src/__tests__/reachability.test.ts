@@ -0,0 +1,95 @@
+import { buildSchema } from '../buildSchema';
+import { unreachableElements } from '../reachability';
+
+function unreachable(sdl: string) {
+  const { types, directives } = unreachableElements(buildSchema(sdl));
+  return { types: types.map((t) => t.name), directives: directives.map((d) => d.name) };
+}
+
+describe('unreachableElements', () => {
+  it('returns nothing when everything is reachable', () => {
+    expect(unreachable(`
+      type Query {
+        node(filter: Filter): Node
+        search: SearchResult
+      }
+
+      interface Node {
+        id: ID!
+      }
+
+      type User implements Node {
+        id: ID!
+        status: Status
+      }
+
+      type Post {
+        title: String
+      }
+
+      union SearchResult = User | Post
+
+      input Filter {
+        status: Status
+      }
+
+      enum Status {
+        ACTIVE
+        DISABLED
+      }
+    `)).toStrictEqual({ types: [], directives: [] });
+  });
+
+  it('reports types not reachable from the root types', () => {
+    expect(unreachable(`
+      type Query {
+        user: User
+      }
+
+      type Mutation {
+        updateUser(input: UserInput): User
+      }
+
+      type User {
+        name: String
+      }
+
+      input UserInput {
+        name: String
+      }
+
+      type LegacyUser {
+        name: String
+        profile: LegacyProfile
+      }
+
+      type LegacyProfile {
+        bio: String
+      }
+
+      input LegacyInput {
+        name: String
+      }
+    `)).toStrictEqual({ types: ['LegacyUser', 'LegacyProfile', 'LegacyInput'], directives: [] });
+  });
+
+  it('reports type system directives that are never applied', () => {
+    expect(unreachable(`
+      directive @used(level: Level) on FIELD_DEFINITION
+      directive @unused(other: Other) on OBJECT
+      directive @executable on FIELD
+
+      type Query {
+        a: Int @used(level: HIGH)
+      }
+
+      enum Level {
+        HIGH
+      }
+
+      input Other {
+        x: Int
+      }
+    `)).toStrictEqual({ types: ['Other'], directives: ['unused'] });
+  });
+});
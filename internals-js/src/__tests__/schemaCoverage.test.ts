@@ -0,0 +1,80 @@
+import { parse } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import { schemaCoverage } from '../schemaCoverage';
+
+const schema = buildSchema(`
+  type Query {
+    me: User
+    search(filter: Filter): [Result]
+    legacy: String
+  }
+
+  interface Node {
+    id: ID!
+  }
+
+  type User implements Node {
+    id: ID!
+    name: String
+    status: Status
+  }
+
+  type Post implements Node {
+    id: ID!
+    title: String
+  }
+
+  union Result = User | Post
+
+  input Filter {
+    text: String
+  }
+
+  enum Status {
+    ACTIVE
+  }
+
+  type Unused {
+    a: Int
+  }
+`);
+
+describe('schemaCoverage', () => {
+  it('reports the exercised and unused elements', () => {
+    const coverage = schemaCoverage(schema, [
+      parse('query Me { me { id name } }'),
+      parse(`
+        query Search($filter: Filter) {
+          search(filter: $filter) {
+            ... on Node {
+              id
+            }
+            ...PostFields
+          }
+        }
+
+        fragment PostFields on Post {
+          title
+        }
+      `),
+      parse('{ me { name } }'),
+    ]);
+
+    expect(coverage.fieldUsages.get('Query.me')).toBe(2);
+    expect(coverage.fieldUsages.get('User.name')).toBe(2);
+    expect(coverage.fieldUsages.get('Node.id')).toBe(1);
+    expect(coverage.unusedFields).toStrictEqual([
+      'Query.legacy',
+      'User.status',
+      'Post.id',
+      'Unused.a',
+    ]);
+    expect(coverage.unusedTypes).toStrictEqual(['Status', 'Unused']);
+  });
+
+  it('reports everything as unused without operations', () => {
+    const coverage = schemaCoverage(schema, []);
+    expect(coverage.unusedTypes).toHaveLength(schema.types().length);
+    expect(coverage.fieldUsages.get('Query.me')).toBe(0);
+  });
+});
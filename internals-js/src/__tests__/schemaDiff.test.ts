@@ -0,0 +1,169 @@
+import { buildSchema } from '../buildSchema';
+import { diffSchemas } from '../schemaDiff';
+
+function changes(oldSdl: string, newSdl: string): [string, string, string][] {
+  return diffSchemas(buildSchema(oldSdl), buildSchema(newSdl)).map((c) => [c.severity, c.kind, c.coordinate]);
+}
+
+describe('diffSchemas', () => {
+  it('returns no change for identical schemas', () => {
+    const sdl = `
+      type Query {
+        a(x: Int = 1): [String!]
+      }
+    `;
+    expect(diffSchemas(buildSchema(sdl), buildSchema(sdl))).toStrictEqual([]);
+  });
+
+  it('classifies type changes', () => {
+    expect(changes(`
+      type Query { a: Int }
+      type Removed { a: Int }
+      type Changed { a: Int }
+    `, `
+      type Query { a: Int }
+      interface Changed { a: Int }
+      type Added { a: Int }
+    `)).toStrictEqual([
+      ['BREAKING', 'TYPE_KIND_CHANGED', 'Changed'],
+      ['BREAKING', 'TYPE_REMOVED', 'Removed'],
+      ['SAFE', 'TYPE_ADDED', 'Added'],
+    ]);
+  });
+
+  it('classifies field changes', () => {
+    expect(changes(`
+      type Query {
+        removed: Int
+        nonNullified: Int
+        listed: [Int]
+        nullified: Int!
+        retyped: Int
+      }
+    `, `
+      type Query {
+        nonNullified: Int!
+        listed: [Int!]!
+        nullified: Int
+        retyped: String
+        added: Int
+      }
+    `)).toStrictEqual([
+      ['BREAKING', 'FIELD_TYPE_CHANGED', 'Query.nullified'],
+      ['BREAKING', 'FIELD_REMOVED', 'Query.removed'],
+      ['BREAKING', 'FIELD_TYPE_CHANGED', 'Query.retyped'],
+      ['SAFE', 'FIELD_ADDED', 'Query.added'],
+      ['SAFE', 'FIELD_TYPE_CHANGED', 'Query.listed'],
+      ['SAFE', 'FIELD_TYPE_CHANGED', 'Query.nonNullified'],
+    ]);
+  });
+
+  it('classifies argument changes', () => {
+    expect(changes(`
+      type Query {
+        f(removed: Int, nullified: Int!, nonNullified: Int, defaulted: Int = 1): Int
+      }
+    `, `
+      type Query {
+        f(nullified: Int, nonNullified: Int!, defaulted: Int = 2, optional: Int, required: Int!, requiredWithDefault: Int! = 0): Int
+      }
+    `)).toStrictEqual([
+      ['BREAKING', 'ARGUMENT_TYPE_CHANGED', 'Query.f(nonNullified:)'],
+      ['BREAKING', 'ARGUMENT_REMOVED', 'Query.f(removed:)'],
+      ['BREAKING', 'ARGUMENT_ADDED', 'Query.f(required:)'],
+      ['DANGEROUS', 'ARGUMENT_DEFAULT_VALUE_CHANGED', 'Query.f(defaulted:)'],
+      ['DANGEROUS', 'ARGUMENT_ADDED', 'Query.f(optional:)'],
+      ['DANGEROUS', 'ARGUMENT_ADDED', 'Query.f(requiredWithDefault:)'],
+      ['SAFE', 'ARGUMENT_TYPE_CHANGED', 'Query.f(nullified:)'],
+    ]);
+  });
+
+  it('classifies input field changes', () => {
+    expect(changes(`
+      type Query { f(i: I): Int }
+      input I {
+        removed: Int
+        a: Int!
+      }
+    `, `
+      type Query { f(i: I): Int }
+      input I {
+        a: Int
+        optional: String
+        required: String!
+      }
+    `)).toStrictEqual([
+      ['BREAKING', 'INPUT_FIELD_REMOVED', 'I.removed'],
+      ['BREAKING', 'INPUT_FIELD_ADDED', 'I.required'],
+      ['DANGEROUS', 'INPUT_FIELD_ADDED', 'I.optional'],
+      ['SAFE', 'INPUT_FIELD_TYPE_CHANGED', 'I.a'],
+    ]);
+  });
+
+  it('classifies enum, union and interface changes', () => {
+    expect(changes(`
+      type Query { e: E, u: U }
+      enum E { A B }
+      union U = T1 | T2
+      interface I { id: ID }
+      interface J { id: ID }
+      type T1 implements I { id: ID }
+      type T2 { id: ID }
+    `, `
+      type Query { e: E, u: U }
+      enum E { A C }
+      union U = T1 | T3
+      interface I { id: ID }
+      interface J { id: ID }
+      type T1 implements J { id: ID }
+      type T2 { id: ID }
+      type T3 { id: ID }
+    `)).toStrictEqual([
+      ['BREAKING', 'ENUM_VALUE_REMOVED', 'E.B'],
+      ['BREAKING', 'IMPLEMENTED_INTERFACE_REMOVED', 'T1'],
+      ['BREAKING', 'UNION_MEMBER_REMOVED', 'U'],
+      ['DANGEROUS', 'ENUM_VALUE_ADDED', 'E.C'],
+      ['DANGEROUS', 'IMPLEMENTED_INTERFACE_ADDED', 'T1'],
+      ['DANGEROUS', 'UNION_MEMBER_ADDED', 'U'],
+      ['SAFE', 'TYPE_ADDED', 'T3'],
+    ]);
+  });
+
+  it('classifies directive changes', () => {
+    expect(changes(`
+      type Query { a: Int }
+      directive @removed on FIELD
+      directive @d(x: Int) repeatable on FIELD | QUERY
+    `, `
+      type Query { a: Int }
+      directive @d(x: Int) on FIELD | MUTATION
+      directive @added on FIELD
+    `)).toStrictEqual([
+      ['BREAKING', 'DIRECTIVE_REPEATABLE_REMOVED', '@d'],
+      ['BREAKING', 'DIRECTIVE_LOCATION_REMOVED', '@d'],
+      ['BREAKING', 'DIRECTIVE_REMOVED', '@removed'],
+      ['SAFE', 'DIRECTIVE_ADDED', '@added'],
+      ['SAFE', 'DIRECTIVE_LOCATION_ADDED', '@d'],
+    ]);
+  });
+
+  it('describes changes', () => {
+    expect(diffSchemas(
+      buildSchema('type Query { f(x: Int = 1): Int, g: Int }'),
+      buildSchema('type Query { f(x: Int = 2): Int, g: Int! }'),
+    ).map((c) => c.message)).toStrictEqual([
+      'Query.f arg x has changed defaultValue from 1 to 2.',
+      'Query.g changed type from Int to Int!.',
+    ]);
+  });
+
+  it('ignores built-in scalars that are no longer referenced', () => {
+    expect(changes(`
+      type Query { a: Float }
+    `, `
+      type Query { a: Int }
+    `)).toStrictEqual([
+      ['BREAKING', 'FIELD_TYPE_CHANGED', 'Query.a'],
+    ]);
+  });
+});
@@ -0,0 +1,145 @@
+import { buildSchema } from '../buildSchema';
+import { schemaDocumentation } from '../schemaDocumentation';
+
+const schema = buildSchema(`
+  type Query {
+    me: User
+  }
+
+  "Things that can be fetched"
+  interface Node {
+    id: ID!
+  }
+
+  "A user"
+  type User implements Node {
+    id: ID!
+    "The user's name"
+    name: String @deprecated(reason: "Use fullName")
+    friends("Max | count" first: Int = 10): [User!]
+  }
+
+  enum Role {
+    ADMIN
+    "Regular user"
+    MEMBER
+  }
+
+  directive @auth(role: Role!) on FIELD_DEFINITION
+`);
+
+describe('schemaDocumentation', () => {
+  it('renders markdown', () => {
+    expect(schemaDocumentation(schema)).toMatchString(`
+      # Schema reference
+
+      ## Types
+
+      ### <a id="Query"></a>Query
+
+      *Object type*
+
+      | Fields | Type | Description |
+      | --- | --- | --- |
+      | \`me\` | [\`User\`](#User) |  |
+
+      ### <a id="Node"></a>Node
+
+      *Interface*
+
+      Things that can be fetched
+
+      | Fields | Type | Description |
+      | --- | --- | --- |
+      | \`id\` | \`ID!\` |  |
+
+      ### <a id="User"></a>User
+
+      *Object type*
+
+      Implements: [\`Node\`](#Node)
+
+      A user
+
+      | Fields | Type | Description |
+      | --- | --- | --- |
+      | \`id\` | \`ID!\` |  |
+      | \`name\` | \`String\` | The user's name<br>**Deprecated**: Use fullName |
+      | \`friends\` | [\`[User!]\`](#User) | Arguments: \`first\`: \`Int\` = 10 (Max \\| count) |
+
+      ### <a id="Role"></a>Role
+
+      *Enum*
+
+      | Values | Description |
+      | --- | --- |
+      | \`ADMIN\` |  |
+      | \`MEMBER\` | Regular user |
+
+      ## Directives
+
+      ### <a id="directive-auth"></a>@auth
+
+      *Directive on FIELD_DEFINITION*
+
+      | Arguments | Type | Description |
+      | --- | --- | --- |
+      | \`role\` | [\`Role!\`](#Role) |  |
+    `);
+  });
+
+  it('renders HTML', () => {
+    const html = schemaDocumentation(schema, { format: 'html', title: 'My <API>' });
+    expect(html).toContain('<h1>My &lt;API&gt;</h1>');
+    expect(html).toContain('<h3 id="User">User</h3>');
+    expect(html).toContain('<p>Implements: <a href="#Node"><code>Node</code></a></p>');
+    expect(html).toContain(
+      "<tr><td><code>name</code></td><td><code>String</code></td><td>The user's name<br><strong>Deprecated</strong>: Use fullName</td></tr>",
+    );
+    expect(html).toContain('<h3 id="directive-auth">@auth</h3>');
+  });
+
+  it('uses distinct anchors for names that only differ by case', () => {
+    const markdown = schemaDocumentation(buildSchema(`
+      type Query {
+        a: User
+        b: user
+      }
+
+      type User {
+        id: ID
+      }
+
+      type user {
+        id: ID
+      }
+    `));
+    expect(markdown).toContain('| `a` | [`User`](#User) |  |');
+    expect(markdown).toContain('| `b` | [`user`](#user) |  |');
+    expect(markdown).toContain('### <a id="User"></a>User');
+    expect(markdown).toContain('### <a id="user"></a>user');
+  });
+
+  it('does not link to the types of core features', () => {
+    const markdown = schemaDocumentation(buildSchema(`
+      schema @link(url: "https://specs.apollo.dev/link/v1.0") {
+        query: Query
+      }
+
+      directive @link(url: String, as: String, for: link__Purpose, import: [link__Import]) repeatable on SCHEMA
+
+      enum link__Purpose {
+        SECURITY
+        EXECUTION
+      }
+
+      scalar link__Import
+
+      type Query {
+        imports: [link__Import]
+      }
+    `));
+    expect(markdown).toContain('| `imports` | `[link__Import]` |  |');
+    expect(markdown).not.toContain('link__Import](');
+  });
+});
@@ -0,0 +1,59 @@
+import { buildSchemaFromAST } from '../buildSchema';
+import { errorCauses } from '../error';
+import { sourceLocation } from '../parsing';
+import { parseSchemaFiles } from '../schemaFiles';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+describe('parseSchemaFiles', () => {
+  let directory: string;
+
+  beforeEach(() => {
+    directory = fs.mkdtempSync(path.join(os.tmpdir(), 'schema-files-'));
+    fs.writeFileSync(path.join(directory, 'query.graphql'), 'type Query {\n  me: User\n}\n');
+    fs.mkdirSync(path.join(directory, 'types'));
+    fs.writeFileSync(path.join(directory, 'types', 'user.gql'), 'type User {\n  id: ID!\n}\n');
+    fs.writeFileSync(path.join(directory, 'README.md'), '# Not a schema');
+  });
+
+  afterEach(() => {
+    fs.rmSync(directory, { recursive: true, force: true });
+  });
+
+  it('parses all the schema files of a directory', () => {
+    const schema = buildSchemaFromAST(parseSchemaFiles(directory));
+    expect(schema.types().map((t) => t.name)).toStrictEqual(['Query', 'User']);
+    expect(sourceLocation(schema.elementByCoordinate('User.id')!.sourceAST!)).toBe(`${path.join(directory, 'types', 'user.gql')}:2:3`);
+  });
+
+  it('parses explicitly listed files', () => {
+    const document = parseSchemaFiles([path.join(directory, 'types', 'user.gql')]);
+    expect(document.definitions).toHaveLength(1);
+  });
+
+  it('reads files reachable through several symbolic links only once', () => {
+    // A link to the parent directory creates a cycle, and a link to `types` makes `user.gql` reachable twice.
+    fs.symlinkSync(directory, path.join(directory, 'types', 'parent'), 'dir');
+    fs.symlinkSync(path.join(directory, 'types'), path.join(directory, 'types-link'), 'dir');
+    const document = parseSchemaFiles(directory);
+    expect(document.definitions).toHaveLength(2);
+    expect(buildSchemaFromAST(document).types().map((t) => t.name)).toStrictEqual(['Query', 'User']);
+  });
+
+  it('reports the errors of all files together', () => {
+    fs.writeFileSync(path.join(directory, 'broken.graphql'), 'type Broken {');
+    fs.writeFileSync(path.join(directory, 'types', 'other.graphql'), 'type Other { a: }');
+    try {
+      parseSchemaFiles(directory);
+      throw new Error('should have thrown');
+    } catch (e) {
+      expect(e.message).toMatch(/^Failed to parse 2 of 4 schema files/);
+      const causes = errorCauses(e)!;
+      expect(causes.map((c) => c.source?.name)).toStrictEqual([
+        path.join(directory, 'broken.graphql'),
+        path.join(directory, 'types', 'other.graphql'),
+      ]);
+    }
+  });
+});
@@ -0,0 +1,54 @@
+import { parse } from 'graphql';
+import { buildSchema } from '../buildSchema';
+import { schemaStats } from '../schemaStats';
+
+describe('schemaStats', () => {
+  const sdl = `
+    directive @cached(ttl: Int) on FIELD_DEFINITION
+
+    "The root – for queries"
+    type Query {
+      "A user"
+      user(id: ID!, login: String): User @cached(ttl: 10)
+    }
+
+    type User {
+      name: String
+      status: Status @deprecated
+    }
+
+    extend type User {
+      email: String
+    }
+
+    enum Status {
+      ACTIVE
+      DISABLED
+    }
+
+    input Filter {
+      status: Status
+    }
+
+    scalar Date
+  `;
+
+  it('computes statistics of a document', () => {
+    expect(schemaStats(parse(sdl))).toStrictEqual({
+      types: 5,
+      fields: 5,
+      arguments: 3,
+      enumValues: 2,
+      directiveDefinitions: 1,
+      directiveApplications: 2,
+      // "The root – for queries" is 24 bytes (the dash takes 3 bytes), and "A user" is 6.
+      descriptionBytes: 30,
+    });
+  });
+
+  it('computes statistics of a schema', () => {
+    const stats = schemaStats(buildSchema(sdl));
+    expect(stats.types).toBe(5);
+    expect(stats.fields).toBe(5);
+  });
+});
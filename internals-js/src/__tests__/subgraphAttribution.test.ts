@@ -0,0 +1,121 @@
+import { parse } from 'graphql';
+import { Supergraph } from '../supergraphs';
+import { attributeOperationFields } from '../subgraphAttribution';
+
+const supergraph = Supergraph.build(`
+  schema
+    @link(url: "https://specs.apollo.dev/link/v1.0")
+    @link(url: "https://specs.apollo.dev/join/v0.3", for: EXECUTION)
+  {
+    query: Query
+  }
+
+  directive @join__enumValue(graph: join__Graph!) repeatable on ENUM_VALUE
+
+  directive @join__field(graph: join__Graph, requires: join__FieldSet, provides: join__FieldSet, type: String, external: Boolean, override: String, usedOverridden: Boolean) repeatable on FIELD_DEFINITION | INPUT_FIELD_DEFINITION
+
+  directive @join__graph(name: String!, url: String!) on ENUM_VALUE
+
+  directive @join__implements(graph: join__Graph!, interface: String!) repeatable on OBJECT | INTERFACE
+
+  directive @join__type(graph: join__Graph!, key: join__FieldSet, extension: Boolean! = false, resolvable: Boolean! = true, isInterfaceObject: Boolean! = false) repeatable on OBJECT | INTERFACE | UNION | ENUM | INPUT_OBJECT | SCALAR
+
+  directive @join__unionMember(graph: join__Graph!, member: String!) repeatable on UNION
+
+  directive @link(url: String, as: String, for: link__Purpose, import: [link__Import]) repeatable on SCHEMA
+
+  scalar join__FieldSet
+
+  enum join__Graph {
+    USERS @join__graph(name: "users", url: "")
+    REVIEWS @join__graph(name: "reviews", url: "")
+  }
+
+  scalar link__Import
+
+  enum link__Purpose {
+    SECURITY
+    EXECUTION
+  }
+
+  type Query
+    @join__type(graph: USERS)
+    @join__type(graph: REVIEWS)
+  {
+    me: User @join__field(graph: USERS)
+    topReviews: [Review] @join__field(graph: REVIEWS)
+  }
+
+  type Review
+    @join__type(graph: REVIEWS)
+  {
+    body: String
+    author: User @join__field(graph: REVIEWS, provides: "name")
+  }
+
+  type User
+    @join__type(graph: USERS, key: "id")
+    @join__type(graph: REVIEWS, key: "id")
+  {
+    id: ID!
+    name: String @join__field(graph: USERS) @join__field(graph: REVIEWS, external: true)
+    reviews: [Review] @join__field(graph: REVIEWS)
+  }
+`);
+
+describe('attributeOperationFields', () => {
+  it('attributes each selected field to the subgraphs resolving it', () => {
+    const operation = parse(`
+      {
+        me {
+          id
+          ...UserInfo
+        }
+        topReviews {
+          text: body
+        }
+        __typename
+      }
+
+      fragment UserInfo on User {
+        name
+        reviews {
+          body
+          author {
+            name
+          }
+        }
+      }
+    `);
+
+    const attributions = attributeOperationFields(supergraph, operation);
+    expect(attributions.map(({ path, coordinate, subgraphs }) => [path.join('.'), coordinate, subgraphs])).toStrictEqual([
+      ['me', 'Query.me', ['users']],
+      ['me.id', 'User.id', ['reviews', 'users']],
+      ['me.name', 'User.name', ['users']],
+      ['me.reviews', 'User.reviews', ['reviews']],
+      ['me.reviews.body', 'Review.body', ['reviews']],
+      ['me.reviews.author', 'Review.author', ['reviews']],
+      ['me.reviews.author.name', 'User.name', ['users']],
+      ['topReviews', 'Query.topReviews', ['reviews']],
+      ['topReviews.text', 'Review.body', ['reviews']],
+    ]);
+  });
+
+  it('selects the operation by name', () => {
+    const document = parse(`
+      query Me { me { id } }
+      query Reviews { topReviews { body } }
+    `);
+    expect(attributeOperationFields(supergraph, document, 'Reviews').map((a) => a.coordinate)).toStrictEqual([
+      'Query.topReviews',
+      'Review.body',
+    ]);
+  });
+
+  it('throws on invalid operations', () => {
+    expect(() => attributeOperationFields(supergraph, parse('{ me { unknown } }'))).toThrow(
+      'Cannot query field "unknown" on type "User".'
+    );
+  });
+});
@@ -0,0 +1,100 @@
+import { buildSchema } from '../buildSchema';
+import { parseOperation } from '../operations';
+import { surrogateKeys } from '../surrogateKeys';
+
+const schema = buildSchema(`
+  directive @cacheTag(name: String!) repeatable on OBJECT | INTERFACE | FIELD_DEFINITION
+
+  type Query {
+    user(id: ID!): User @cacheTag(name: "user-lookup")
+    products: [Product]
+    version: String
+  }
+
+  type User @cacheTag(name: "users") {
+    id: ID!
+    name: String
+    reviews: [Review] @cacheTag(name: "user-reviews")
+  }
+
+  type Review @cacheTag(name: "reviews") @cacheTag(name: "ugc") {
+    body: String
+  }
+
+  interface Product @cacheTag(name: "products") {
+    upc: ID!
+  }
+
+  type Book implements Product @cacheTag(name: "books") {
+    upc: ID!
+    title: String
+  }
+`);
+
+describe('surrogateKeys', () => {
+  it('collects the tags of fields, their parent types and their return types', () => {
+    const operation = parseOperation(schema, `
+      {
+        user(id: 1) {
+          name
+          reviews {
+            body
+          }
+        }
+      }
+    `);
+    expect(surrogateKeys(operation)).toStrictEqual(['reviews', 'ugc', 'user-lookup', 'user-reviews', 'users']);
+  });
+
+  it('only includes the tags of resolved fields', () => {
+    const operation = parseOperation(schema, `
+      {
+        version
+        user(id: 1) {
+          name
+        }
+      }
+    `);
+    expect(surrogateKeys(operation)).toStrictEqual(['user-lookup', 'users']);
+  });
+
+  it('handles fragments', () => {
+    const operation = parseOperation(schema, `
+      {
+        products {
+          ...ProductFields
+        }
+      }
+
+      fragment ProductFields on Product {
+        upc
+        ... on Book {
+          title
+        }
+      }
+    `);
+    expect(surrogateKeys(operation)).toStrictEqual(['books', 'products']);
+  });
+
+  it('returns no keys when the schema has no cache tag directive', () => {
+    const schemaWithoutTags = buildSchema(`
+      type Query {
+        version: String
+      }
+    `);
+    expect(surrogateKeys(parseOperation(schemaWithoutTags, '{ version }'))).toStrictEqual([]);
+  });
+
+  it('supports custom directive and argument names', () => {
+    const customSchema = buildSchema(`
+      directive @surrogate(key: String!) on FIELD_DEFINITION
+
+      type Query {
+        version: String @surrogate(key: "version")
+      }
+    `);
+    const operation = parseOperation(customSchema, '{ version }');
+    expect(surrogateKeys(operation)).toStrictEqual([]);
+    expect(surrogateKeys(operation, { directiveName: 'surrogate', argumentName: 'key' })).toStrictEqual(['version']);
+  });
+});
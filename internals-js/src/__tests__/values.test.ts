@@ -350,6 +350,69 @@ describe('default value validation', () => {
   });
 });
 
+describe('@oneOf input objects', () => {
+  it('identifies OneOf input objects', () => {
+    const schema = parseSchema(`
+      directive @oneOf on INPUT_OBJECT
+
+      type Query {
+        f(i: I, j: J): Int
+      }
+
+      input I @oneOf {
+        a: Int
+        b: String
+      }
+
+      input J {
+        a: Int
+      }
+    `);
+    const i = schema.type('I');
+    const j = schema.type('J');
+    expect(i?.kind === 'InputObjectType' && i.isOneOf()).toBe(true);
+    expect(j?.kind === 'InputObjectType' && j.isOneOf()).toBe(false);
+  });
+
+  it('errors on non-nullable or defaulted fields', () => {
+    const doc = gql`
+      directive @oneOf on INPUT_OBJECT
+
+      input I @oneOf {
+        a: Int!
+        b: Int = 1
+      }
+    `;
+
+    expect(buildForErrors(doc)).toStrictEqual([
+      ['INVALID_GRAPHQL', '[S] OneOf input field I.a must be nullable.'],
+      ['INVALID_GRAPHQL', '[S] OneOf input field I.b cannot have a default value.'],
+    ]);
+  });
+
+  it('errors on values not providing exactly one non-null field', () => {
+    const doc = gql`
+      directive @oneOf on INPUT_OBJECT
+
+      type Query {
+        f(i: I = { a: 1, b: "x" }): Int
+        g(i: I = { a: null }): Int
+        h(i: I = { b: "x" }): Int
+      }
+
+      input I @oneOf {
+        a: Int
+        b: String
+      }
+    `;
+
+    expect(buildForErrors(doc)).toStrictEqual([
+      ['INVALID_GRAPHQL', '[S] Invalid default value (got: {a: 1, b: "x"}) provided for argument Query.f(i:) of type I.'],
+      ['INVALID_GRAPHQL', '[S] Invalid default value (got: {a: null}) provided for argument Query.g(i:) of type I.'],
+    ]);
+  });
+});
+
 describe('values printing', () => {
   it('prints enums value correctly within multiple lists', () => {
     const sdl = `
@@ -7,7 +7,6 @@ import {
   FieldDefinitionNode,
   GraphQLError,
   InputValueDefinitionNode,
-  parse,
   SchemaDefinitionNode,
   Source,
   TypeNode,
@@ -56,6 +55,8 @@ import {
 } from "./definitions";
 import { ERRORS, errorCauses, withModifiedErrorNodes } from "./error";
 import { introspectionTypeNames } from "./introspection";
+import { parseWithLimits, ParserOptions } from "./parsing";
+import { suggestionList, withSuggestions } from "./suggestions";
 
 function buildValue(value?: ValueNode): any {
   return value ? valueFromASTUntyped(value) : undefined;
@@ -64,10 +65,12 @@ function buildValue(value?: ValueNode): any {
 export type BuildSchemaOptions = {
   blueprint?: SchemaBlueprint,
   validate?: boolean,
+  // Limits applied when parsing the schema source. Ignored by `buildSchemaFromAST`.
+  parserOptions?: ParserOptions,
 }
 
 export function buildSchema(source: string | Source, options?: BuildSchemaOptions): Schema {
-  return buildSchemaFromAST(parse(source), options);
+  return buildSchemaFromAST(parseWithLimits(source, options?.parserOptions), options);
 }
 
 export function buildSchemaFromAST(
@@ -290,7 +293,8 @@ function withoutTrailingDefinition(str: string): NamedTypeKind {
 function getReferencedType(node: NamedTypeNode, schema: Schema): NamedType {
   const type = schema.type(node.name.value);
   if (!type) {
-    throw ERRORS.INVALID_GRAPHQL.err(`Unknown type ${node.name.value}`, { nodes: node });
+    const suggestions = suggestionList(node.name.value, schema.allTypes().map((t) => t.name));
+    throw ERRORS.INVALID_GRAPHQL.err(withSuggestions(`Unknown type ${node.name.value}`, suggestions), { nodes: node });
   }
   return type;
 }
@@ -0,0 +1,126 @@
+import {
+  DirectiveDefinition,
+  ErrGraphQLAPISchemaValidationFailed,
+  NamedType,
+  Schema,
+  SchemaElement,
+} from "./definitions";
+import { ERRORS } from "./error";
+import { INACCESSIBLE_VERSIONS, inaccessibleIdentity } from "./specs/inaccessibleSpec";
+import { tagIdentity } from "./specs/tagSpec";
+
+export type ContractOptions = {
+  // If non-empty, only the object types, interfaces and unions (and the fields of object types and interfaces) that are
+  // tagged with one of those tags (or whose parent type is) are kept in the contract.
+  include?: readonly string[],
+  // The elements tagged with any of those tags are removed from the contract. This takes precedence over `include`.
+  exclude?: readonly string[],
+}
+
+/**
+ * Computes the API schema of a contract of the provided supergraph, that is the API schema of the supergraph with some
+ * elements removed based on their `@tag` applications.
+ *
+ * Elements are removed by marking them `@inaccessible` in a copy of the supergraph before computing its API schema, so
+ * the usual `@inaccessible` validations apply: in particular, if a kept element references a removed one (say, a kept
+ * field whose type is removed), an error is thrown.
+ */
+export function contractAPISchema(supergraph: Schema, options: ContractOptions): Schema {
+  const include = new Set(options.include ?? []);
+  const exclude = new Set(options.exclude ?? []);
+
+  const schema = supergraph.clone();
+  const coreFeatures = schema.coreFeatures;
+  if (!coreFeatures) {
+    throw ERRORS.INVALID_GRAPHQL.err('Cannot compute a contract of a schema that is not a supergraph (it does not @link any feature)');
+  }
+
+  const tagFeature = coreFeatures.getByIdentity(tagIdentity);
+  const tagDirective = tagFeature ? schema.directive(tagFeature.directiveNameInSchema('tag')) : undefined;
+  const tagsOf = (element: SchemaElement<any, any>): string[] => tagDirective
+    ? element.appliedDirectivesOf(tagDirective).map((application) => application.arguments().name)
+    : [];
+  const isTagged = (element: SchemaElement<any, any>, tags: Set<string>) => tagsOf(element).some((tag) => tags.has(tag));
+
+  const inaccessibleDirective = inaccessibleDirectiveOf(schema);
+  const remove = (element: SchemaElement<any, any>) => {
+    if (!element.hasAppliedDirective(inaccessibleDirective)) {
+      element.applyDirective(inaccessibleDirective);
+    }
+  };
+
+  for (const type of schema.types()) {
+    if (isFeatureElement(schema, type)) {
+      continue;
+    }
+    if (isTagged(type, exclude)) {
+      remove(type);
+      continue;
+    }
+
+    switch (type.kind) {
+      case 'ObjectType':
+      case 'InterfaceType':
+        const typeIncluded = isTagged(type, include);
+        let hasIncludedField = false;
+        for (const field of type.fields()) {
+          if (isTagged(field, exclude) || (include.size > 0 && !typeIncluded && !isTagged(field, include))) {
+            remove(field);
+          } else {
+            hasIncludedField = true;
+          }
+          for (const arg of field.arguments()) {
+            if (isTagged(arg, exclude)) {
+              remove(arg);
+            }
+          }
+        }
+        if (include.size > 0 && !typeIncluded && !hasIncludedField) {
+          remove(type);
+        }
+        break;
+      case 'UnionType':
+        if (include.size > 0 && !isTagged(type, include)) {
+          remove(type);
+        }
+        break;
+      case 'EnumType':
+        for (const value of type.values) {
+          if (isTagged(value, exclude)) {
+            remove(value);
+          }
+        }
+        break;
+      case 'InputObjectType':
+        for (const field of type.fields()) {
+          if (isTagged(field, exclude)) {
+            remove(field);
+          }
+        }
+        break;
+      case 'ScalarType':
+        break;
+    }
+  }
+
+  return schema.toAPISchema();
+}
+
+function isFeatureElement(schema: Schema, type: NamedType): boolean {
+  return !!schema.coreFeatures?.sourceFeature(type);
+}
+
+function inaccessibleDirectiveOf(schema: Schema): DirectiveDefinition {
+  const coreFeatures = schema.coreFeatures!;
+  let feature = coreFeatures.getByIdentity(inaccessibleIdentity);
+  if (!feature) {
+    // The supergraph does not use `@inaccessible` yet, so we link it.
+    const spec = INACCESSIBLE_VERSIONS.latest();
+    const errors = coreFeatures.coreDefinition.applyFeatureToSchema(schema, spec, undefined, spec.defaultCorePurpose);
+    if (errors.length > 0) {
+      throw ErrGraphQLAPISchemaValidationFailed(errors);
+    }
+    feature = coreFeatures.getByIdentity(inaccessibleIdentity)!;
+  }
+  return schema.directive(feature.directiveNameInSchema('inaccessible'))!;
+}
@@ -32,7 +32,7 @@ import {
   isCoreSpecDirectiveApplication,
   removeAllCoreFeatures,
 } from "./specs/coreSpec";
-import { assert, mapValues, MapWithCachedArrays, removeArrayElement } from "./utils";
+import { assert, joinStrings, mapValues, MapWithCachedArrays, removeArrayElement } from "./utils";
 import {
   withDefaultValues,
   valueEquals,
@@ -52,7 +52,7 @@ import { SDLValidationRule } from "graphql/validation/ValidationContext";
 import { specifiedSDLRules } from "graphql/validation/specifiedRules";
 import { validateSchema } from "./validate";
 import { createDirectiveSpecification, createScalarTypeSpecification, DirectiveSpecification, TypeSpecification } from "./directiveAndTypeSpecification";
-import { didYouMean, suggestionList } from "./suggestions";
+import { didYouMean, suggestionList, withSuggestions } from "./suggestions";
 import { aggregateError, ERRORS, withModifiedErrorMessage } from "./error";
 import { coreFeatureDefinitionIfKnown } from "./knownCoreFeatures";
 
@@ -339,7 +339,8 @@ export function typeFromAST(schema: Schema, node: TypeNode): Type {
     default:
       const type = schema.type(node.name.value);
       if (!type) {
-        throw ERRORS.INVALID_GRAPHQL.err(`Unknown type "${node.name.value}"`, { nodes: node });
+        const suggestions = suggestionList(node.name.value, schema.allTypes().map((t) => t.name));
+        throw ERRORS.INVALID_GRAPHQL.err(withSuggestions(`Unknown type "${node.name.value}"`, suggestions), { nodes: node });
       }
       return type;
   }
@@ -933,15 +934,23 @@ export class SchemaBlueprint {
    * Allows to intercept some graphQL-js error messages when we can provide additional guidance to users.
    */
   onGraphQLJSValidationError(schema: Schema, error: GraphQLError): GraphQLError {
-    // For now, the main additional guidance we provide is around directives, where we could provide additional help in 2 main ways:
+    // For now, the main additional guidance we provide is around directives, where we could provide additional help in 3 main ways:
     // - if a directive name is likely misspelled (somehow, graphQL-js has methods to offer suggestions on likely mispelling, but don't use this (at the
     //   time of this writting) for directive names).
     // - for fed 2 schema, if a federation directive is refered under it's "default" naming but is not properly imported (not enforced
     //   in the method but rather in the `FederationBlueprint`).
+    // - if a directive is applied at a location it does not support, by listing the locations it does support (graphQL-js only mentions
+    //   the location where the directive was used).
     //
     // Note that intercepting/parsing error messages to modify them is never ideal, but pragmatically, it's probably better than rewriting the relevant
     // rules entirely (in that later case, our "copied" rule would stop getting any potential graphQL-js made improvements for instance). And while such
     // parsing is fragile, in that it'll break if the original message change, we have unit tests to surface any such breakage so it's not really a risk.
+    const misplacedMatcher = /^Directive "@(?<directive>[_A-Za-z][_0-9A-Za-z]*)" may not be used on [A-Z_]+\.$/.exec(error.message);
+    const misplacedName = misplacedMatcher?.groups?.directive;
+    if (misplacedName) {
+      return this.onMisplacedDirectiveValidationError(schema, misplacedName, error);
+    }
+
     const matcher = /^Unknown directive "@(?<directive>[_A-Za-z][_0-9A-Za-z]*)"\.$/.exec(error.message);
     const name = matcher?.groups?.directive;
     if (!name) {
@@ -961,6 +970,15 @@ export class SchemaBlueprint {
     return error;
   }
 
+  onMisplacedDirectiveValidationError(schema: Schema, directiveName: string, error: GraphQLError): GraphQLError {
+    const definition = schema.directive(directiveName);
+    if (!definition || definition.locations.length === 0) {
+      return error;
+    }
+    const allowed = joinStrings(definition.locations.concat(), ', ', undefined, ' or ');
+    return withModifiedErrorMessage(error, `${error.message} It can only be used on ${allowed}.`);
+  }
+
   applyDirectivesAfterParsing() {
     return false;
   }
@@ -1852,6 +1870,13 @@ export class ScalarType extends BaseNamedType<OutputTypeReferencer | InputTypeRe
   readonly kind = 'ScalarType' as const;
   readonly astDefinitionKind = Kind.SCALAR_TYPE_DEFINITION;
 
+  /**
+   * The URL of the specification of this scalar, as provided by `@specifiedBy`, if any.
+   */
+  specifiedByURL(): string | undefined {
+    return this.appliedDirectivesOf(this.schema().specifiedByDirective())[0]?.arguments().url;
+  }
+
   protected removeTypeReference(type: NamedType) {
     assert(false, `Scalar type ${this} can't reference other types; shouldn't be asked to remove reference to ${type}`);
   }
@@ -2101,6 +2126,14 @@ export class ObjectType extends FieldBasedType<ObjectType, ObjectTypeReferencer>
     return schema.schemaDefinition.root('query')?.type === this;
   }
 
+  /**
+   *  Whether this type is the "mutation" root type of the schema (will return false if the type is detached).
+   */
+  isMutationRootType(): boolean {
+    const schema = this.schema();
+    return schema.schemaDefinition.root('mutation')?.type === this;
+  }
+
   /**
    *  Whether this type is the "subscription" root type of the schema (will return false if the type is detached).
    */
@@ -2377,6 +2410,17 @@ export class InputObjectType extends BaseNamedType<InputTypeReferencer, InputObj
     return this._fields.get(name);
   }
 
+  /**
+   * Whether this is a "OneOf" input object (one with `@oneOf` applied), for which exactly one field must be provided (with
+   * a non-null value).
+   *
+   * Note that `@oneOf` is not yet part of the built-in directives of the spec, so schemas using it need to define it
+   * (`directive @oneOf on INPUT_OBJECT`).
+   */
+  isOneOf(): boolean {
+    return this.hasAppliedDirective('oneOf');
+  }
+
   addField(field: InputFieldDefinition): InputFieldDefinition;
   addField(name: string, type?: Type): InputFieldDefinition;
   addField(nameOrField: string | InputFieldDefinition, type?: Type): InputFieldDefinition {
@@ -0,0 +1,143 @@
+import {
+  ASTNode,
+  DEFAULT_DEPRECATION_REASON,
+  DocumentNode,
+  getNamedType,
+  TypeInfo,
+  visit,
+  visitWithTypeInfo,
+} from "graphql";
+import {
+  ArgumentDefinition,
+  EnumValue,
+  FieldDefinition,
+  InputFieldDefinition,
+  Schema,
+} from "./definitions";
+
+export type DeprecatableElement = FieldDefinition<any> | InputFieldDefinition | ArgumentDefinition<any> | EnumValue;
+
+export type Deprecation = {
+  // The coordinate of the deprecated element.
+  coordinate: string,
+  reason: string,
+  // The AST nodes of the deprecated element, if the schema was built from an AST.
+  nodes: ASTNode[],
+}
+
+export type DeprecatedUsage = Deprecation & {
+  // The name of the operation using the deprecated element, if the usage is in a named operation (usages in fragments are
+  // reported without operation name).
+  operationName?: string,
+  // The AST nodes of the usage in the operation document.
+  usageNodes: ASTNode[],
+}
+
+/**
+ * The deprecation reason of the provided element if it is deprecated (which defaults to "No longer supported" if the
+ * `@deprecated` application has no explicit reason), or `undefined` if it is not deprecated.
+ */
+export function deprecationReason(element: DeprecatableElement): string | undefined {
+  const application = element.appliedDirectivesOf(element.schema().deprecatedDirective())[0];
+  return application ? (application.arguments().reason ?? DEFAULT_DEPRECATION_REASON) : undefined;
+}
+
+function toDeprecation(element: DeprecatableElement): Deprecation | undefined {
+  const reason = deprecationReason(element);
+  return reason === undefined
+    ? undefined
+    : { coordinate: element.coordinate, reason, nodes: element.sourceAST ? [element.sourceAST] : [] };
+}
+
+/**
+ * Returns all the deprecated fields, input fields, arguments and enum values of the provided schema, in schema order.
+ */
+export function schemaDeprecations(schema: Schema): Deprecation[] {
+  const elements: DeprecatableElement[] = [];
+  for (const type of schema.types()) {
+    switch (type.kind) {
+      case 'ObjectType':
+      case 'InterfaceType':
+        for (const field of type.fields()) {
+          elements.push(field, ...field.arguments());
+        }
+        break;
+      case 'InputObjectType':
+        elements.push(...type.fields());
+        break;
+      case 'EnumType':
+        elements.push(...type.values);
+        break;
+    }
+  }
+  for (const directive of schema.directives()) {
+    elements.push(...directive.arguments());
+  }
+  return elements.map(toDeprecation).filter((d): d is Deprecation => d !== undefined);
+}
+
+/**
+ * Returns the usages of deprecated elements of the provided schema in the provided operation documents, in document
+ * order.
+ *
+ * Usages are found for fields, arguments (of fields and directives), and for input object fields and enum values used
+ * in literal values. Documents are expected to be valid against the schema: elements that are not found in the schema
+ * are ignored.
+ */
+export function deprecatedUsages(schema: Schema, documents: readonly DocumentNode[]): DeprecatedUsage[] {
+  const usages: DeprecatedUsage[] = [];
+  const typeInfo = new TypeInfo(schema.toGraphQLJSSchema());
+  let operationName: string | undefined = undefined;
+
+  const addUsage = (coordinate: string, node: ASTNode) => {
+    // The coordinates we build always point to fields, input fields, arguments or enum values.
+    const element = schema.elementByCoordinate(coordinate) as DeprecatableElement | undefined;
+    const deprecation = element ? toDeprecation(element) : undefined;
+    if (deprecation) {
+      usages.push({ ...deprecation, operationName, usageNodes: [node] });
+    }
+  };
+
+  for (const document of documents) {
+    visit(document, visitWithTypeInfo(typeInfo, {
+      OperationDefinition: {
+        enter(node) {
+          operationName = node.name?.value;
+        },
+        leave() {
+          operationName = undefined;
+        },
+      },
+      Field(node) {
+        const parentType = typeInfo.getParentType();
+        // Introspection fields (like `__typename`, which can be queried on unions) are never deprecated.
+        if (parentType && typeInfo.getFieldDef() && !node.name.value.startsWith('__')) {
+          addUsage(`${parentType.name}.${node.name.value}`, node);
+        }
+      },
+      Argument(node) {
+        const directive = typeInfo.getDirective();
+        const parentType = typeInfo.getParentType();
+        const field = typeInfo.getFieldDef();
+        if (directive) {
+          addUsage(`@${directive.name}(${node.name.value}:)`, node);
+        } else if (parentType && field) {
+          addUsage(`${parentType.name}.${field.name}(${node.name.value}:)`, node);
+        }
+      },
+      ObjectField(node) {
+        const parentInputType = typeInfo.getParentInputType();
+        if (parentInputType) {
+          addUsage(`${getNamedType(parentInputType).name}.${node.name.value}`, node);
+        }
+      },
+      EnumValue(node) {
+        const inputType = typeInfo.getInputType();
+        if (inputType) {
+          addUsage(`${getNamedType(inputType).name}.${node.value}`, node);
+        }
+      },
+    }));
+  }
+  return usages;
+}
@@ -0,0 +1,121 @@
+import { ASTNode, getLocation, GraphQLError } from "graphql";
+import { errorCauses, errorCode } from "./error";
+import { LintWarning } from "./lint";
+
+export type DiagnosticSeverity = 'error' | 'warning' | 'note';
+
+export type DiagnosticLocation = {
+  // The name of the source the location is in (the file name, when parsed with `parseSources`).
+  source: string,
+  // The subgraph the location is in, for the nodes of subgraphs (see `SubgraphASTNode`).
+  subgraph?: string,
+  line: number,
+  column: number,
+}
+
+/**
+ * A tool-agnostic representation of an error, warning or hint, which can be serialized with `diagnosticsToJSON` or
+ * `diagnosticsToSARIF`.
+ */
+export type Diagnostic = {
+  code: string,
+  severity: DiagnosticSeverity,
+  message: string,
+  // The coordinate of the schema element the diagnostic is about, if any.
+  coordinate?: string,
+  locations: DiagnosticLocation[],
+}
+
+export function diagnosticLocations(nodes: readonly ASTNode[] | undefined): DiagnosticLocation[] {
+  const locations: DiagnosticLocation[] = [];
+  for (const node of nodes ?? []) {
+    if (node.loc) {
+      const { line, column } = getLocation(node.loc.source, node.loc.start);
+      const subgraph = 'subgraph' in node ? (node as { subgraph: string }).subgraph : undefined;
+      locations.push({ source: node.loc.source.name, subgraph, line, column });
+    }
+  }
+  return locations;
+}
+
+/**
+ * Converts an error (from parsing, validation or composition) to diagnostics: one per cause for errors aggregating
+ * multiple errors (like the ones thrown when building an invalid schema), and a single one otherwise. Errors with no code
+ * (in their extensions) get the `GRAPHQL_ERROR` code.
+ */
+export function diagnosticsFromError(error: GraphQLError): Diagnostic[] {
+  return (errorCauses(error) ?? [error]).map((cause): Diagnostic => {
+    let locations = diagnosticLocations(cause.nodes);
+    if (locations.length === 0 && cause.locations) {
+      // Errors with no nodes, like syntax errors, may still have locations in their source.
+      const source = cause.source?.name ?? 'GraphQL request';
+      locations = cause.locations.map(({ line, column }) => ({ source, line, column }));
+    }
+    return {
+      code: errorCode(cause) ?? 'GRAPHQL_ERROR',
+      severity: 'error',
+      message: cause.message,
+      locations,
+    };
+  });
+}
+
+export function diagnosticFromLintWarning(warning: LintWarning): Diagnostic {
+  return {
+    code: warning.rule,
+    severity: 'warning',
+    message: warning.message,
+    coordinate: warning.coordinate,
+    locations: diagnosticLocations(warning.nodes),
+  };
+}
+
+export function diagnosticsToJSON(diagnostics: readonly Diagnostic[]): string {
+  return JSON.stringify(diagnostics, null, 2);
+}
+
+export type SARIFOptions = {
+  // The name of the tool reported in the SARIF log. Defaults to `@apollo/federation-internals`.
+  toolName?: string,
+  toolVersion?: string,
+}
+
+/**
+ * Serializes the provided diagnostics as a SARIF 2.1.0 log (with a single run), the format read by most code scanning
+ * and code review tools.
+ *
+ * The codes of the diagnostics are used as rule ids, and their source names as artifact URIs (so sources should be named
+ * after the files they were read from, relative to the repository root, for tools to be able to annotate those files).
+ * The coordinate of a diagnostic, if any, is reported as its logical location.
+ */
+export function diagnosticsToSARIF(diagnostics: readonly Diagnostic[], options: SARIFOptions = {}): string {
+  const ruleIds = Array.from(new Set(diagnostics.map((d) => d.code)));
+  const results = diagnostics.map((diagnostic) => ({
+    ruleId: diagnostic.code,
+    ruleIndex: ruleIds.indexOf(diagnostic.code),
+    level: diagnostic.severity,
+    message: { text: diagnostic.message },
+    locations: diagnostic.locations.map((location) => ({
+      physicalLocation: {
+        artifactLocation: { uri: location.source },
+        region: { startLine: location.line, startColumn: location.column },
+      },
+    })),
+    logicalLocations: diagnostic.coordinate ? [{ fullyQualifiedName: diagnostic.coordinate }] : undefined,
+  }));
+  const log = {
+    $schema: 'https://json.schemastore.org/sarif-2.1.0.json',
+    version: '2.1.0',
+    runs: [{
+      tool: {
+        driver: {
+          name: options.toolName ?? '@apollo/federation-internals',
+          version: options.toolVersion,
+          rules: ruleIds.map((id) => ({ id })),
+        },
+      },
+      results,
+    }],
+  };
+  return JSON.stringify(log, null, 2);
+}
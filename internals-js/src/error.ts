@@ -674,6 +674,12 @@ const SOURCE_FIELD_NOT_ON_ROOT_OR_ENTITY_FIELD = makeCodeDefinition(
   { addedIn: '2.7.0' },
 );
 
+const SCHEMA_SIZE_LIMIT_EXCEEDED = makeCodeDefinition(
+  'SCHEMA_SIZE_LIMIT_EXCEEDED',
+  'The composed supergraph exceeds one of the hard schema size limits (number of types, number of fields or size of the supergraph SDL) configured for composition.',
+  { addedIn: '2.8.0' },
+);
+
 export const ERROR_CATEGORIES = {
   DIRECTIVE_FIELDS_MISSING_EXTERNAL,
   DIRECTIVE_UNSUPPORTED_ON_INTERFACE,
@@ -782,6 +788,7 @@ export const ERRORS = {
   SOURCE_FIELD_HTTP_BODY_INVALID,
   SOURCE_FIELD_SELECTION_INVALID,
   SOURCE_FIELD_NOT_ON_ROOT_OR_ENTITY_FIELD,
+  SCHEMA_SIZE_LIMIT_EXCEEDED,
 };
 
 const codeDefByCode = Object.values(ERRORS).reduce((obj: {[code: string]: ErrorCodeDefinition}, codeDef: ErrorCodeDefinition) => { obj[codeDef.code] = codeDef; return obj; }, {});
@@ -1564,6 +1564,12 @@ export function parseFieldSetArgument({
       e,
       (msg: string) => {
         if (msg.startsWith('Cannot query field')) {
+          // The field is usually missing because it is defined in another subgraph, in which case suggesting similarly
+          // named fields of this subgraph would be misleading, so we replace any suggestion by our own guidance.
+          const suggestionsIdx = msg.indexOf(' Did you mean ');
+          if (suggestionsIdx >= 0) {
+            msg = msg.slice(0, suggestionsIdx);
+          }
           if (msg.endsWith('.')) {
             msg = msg.slice(0, msg.length - 1);
           }
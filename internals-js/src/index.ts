@@ -24,3 +24,18 @@ export * from './specs/authenticatedSpec';
 export * from './specs/requiresScopesSpec';
 export * from './specs/policySpec';
 export * from './specs/sourceSpec';
+export * from './contracts';
+export * from './deprecations';
+export * from './diagnostics';
+export * from './lint';
+export * from './normalization';
+export * from './operationAnalysis';
+export * from './operationSignature';
+export * from './parsing';
+export * from './reachability';
+export * from './schemaCoverage';
+export * from './schemaDiff';
+export * from './schemaDocumentation';
+export * from './schemaStats';
+export * from './subgraphAttribution';
+export * from './surrogateKeys';
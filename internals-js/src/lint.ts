@@ -0,0 +1,151 @@
+import { ASTNode } from "graphql";
+import {
+  ArgumentDefinition,
+  DirectiveDefinition,
+  EnumValue,
+  FieldDefinition,
+  InputFieldDefinition,
+  isNonNullType,
+  NamedSchemaElement,
+  NamedType,
+  Schema,
+} from "./definitions";
+import { FEDERATION_OPERATION_TYPES, isFederationField, isFederationSubgraphSchema } from "./federation";
+
+export type LintWarning = {
+  // The name of the rule that raised the warning.
+  rule: string,
+  // The coordinate of the element the warning is about.
+  coordinate: string,
+  message: string,
+  // The AST nodes of the element the warning is about, if the schema was built from an AST (use `sourceLocation` to get
+  // a printable position).
+  nodes: ASTNode[],
+}
+
+export type LintReporter = (element: NamedSchemaElement<any, any, any>, message: string) => void;
+
+/**
+ * A lint rule, which gets called on each (non built-in) element of the linted schema it has a visitor for, and reports
+ * warnings using the provided reporter.
+ */
+export type LintRule = {
+  name: string,
+  type?: (type: NamedType, report: LintReporter) => void,
+  // Called on the fields of object types, interfaces and input objects.
+  field?: (field: FieldDefinition<any> | InputFieldDefinition, report: LintReporter) => void,
+  // Called on the arguments of fields and directive definitions.
+  argument?: (argument: ArgumentDefinition<any>, report: LintReporter) => void,
+  enumValue?: (value: EnumValue, report: LintReporter) => void,
+  directive?: (directive: DirectiveDefinition, report: LintReporter) => void,
+}
+
+export const TYPE_NAMES_PASCAL_CASE: LintRule = {
+  name: 'TYPE_NAMES_PASCAL_CASE',
+  type: (type, report) => {
+    if (!/^[A-Z][a-zA-Z0-9]*$/.test(type.name)) {
+      report(type, `Type "${type.name}" should be named in PascalCase.`);
+    }
+  },
+};
+
+export const FIELDS_HAVE_DESCRIPTIONS: LintRule = {
+  name: 'FIELDS_HAVE_DESCRIPTIONS',
+  field: (field, report) => {
+    if (!field.description?.trim()) {
+      report(field, `Field "${field.coordinate}" should have a description.`);
+    }
+  },
+};
+
+export const ENUM_VALUES_SCREAMING_CASE: LintRule = {
+  name: 'ENUM_VALUES_SCREAMING_CASE',
+  enumValue: (value, report) => {
+    if (!/^[A-Z][A-Z0-9_]*$/.test(value.name)) {
+      report(value, `Enum value "${value.coordinate}" should be named in SCREAMING_SNAKE_CASE.`);
+    }
+  },
+};
+
+export const NO_BOOLEAN_FIELD_PREFIXES: LintRule = {
+  name: 'NO_BOOLEAN_FIELD_PREFIXES',
+  field: (field, report) => {
+    const type = field.type && isNonNullType(field.type) ? field.type.ofType : field.type;
+    const match = field.name.match(/^(is|has)[A-Z]/);
+    if (match && type?.kind === 'ScalarType' && type.name === 'Boolean') {
+      report(field, `Boolean field "${field.coordinate}" should not be prefixed by "${match[1]}".`);
+    }
+  },
+};
+
+export const DEFAULT_LINT_RULES: readonly LintRule[] = [
+  TYPE_NAMES_PASCAL_CASE,
+  FIELDS_HAVE_DESCRIPTIONS,
+  ENUM_VALUES_SCREAMING_CASE,
+  NO_BOOLEAN_FIELD_PREFIXES,
+];
+
+/**
+ * Lints the provided schema with the provided rules (by default, `DEFAULT_LINT_RULES`), returning the warnings of each
+ * rule (in the order of the rules) in schema order.
+ *
+ * Built-in elements, the elements of the core features linked by the schema (like `link__Import`) and, in subgraphs, the
+ * types and fields federation adds (like `_Service` or `Query._entities`) are not linted, since users have no control
+ * over them.
+ */
+export function lintSchema(schema: Schema, rules: readonly LintRule[] = DEFAULT_LINT_RULES): LintWarning[] {
+  const warnings: LintWarning[] = [];
+  const isSubgraph = isFederationSubgraphSchema(schema);
+  const isGeneratedElement = (element: NamedType | DirectiveDefinition) => !!schema.coreFeatures?.sourceFeature(element)
+    || (isSubgraph && FEDERATION_OPERATION_TYPES.some((spec) => spec.name === element.name));
+
+  for (const rule of rules) {
+    const report: LintReporter = (element, message) => {
+      warnings.push({
+        rule: rule.name,
+        coordinate: element.coordinate,
+        message,
+        nodes: element.sourceAST ? [element.sourceAST] : [],
+      });
+    };
+    const visitArguments = (args: readonly ArgumentDefinition<any>[]) => {
+      if (rule.argument) {
+        args.forEach((arg) => rule.argument!(arg, report));
+      }
+    };
+
+    for (const type of schema.types()) {
+      if (isGeneratedElement(type)) {
+        continue;
+      }
+      rule.type?.(type, report);
+      switch (type.kind) {
+        case 'ObjectType':
+        case 'InterfaceType':
+          for (const field of type.fields()) {
+            if (isSubgraph && isFederationField(field)) {
+              continue;
+            }
+            rule.field?.(field, report);
+            visitArguments(field.arguments());
+          }
+          break;
+        case 'InputObjectType':
+          type.fields().forEach((field) => rule.field?.(field, report));
+          break;
+        case 'EnumType':
+          type.values.forEach((value) => rule.enumValue?.(value, report));
+          break;
+      }
+    }
+
+    for (const directive of schema.directives()) {
+      if (isGeneratedElement(directive)) {
+        continue;
+      }
+      rule.directive?.(directive, report);
+      visitArguments(directive.arguments());
+    }
+  }
+  return warnings;
+}
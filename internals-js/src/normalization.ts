@@ -0,0 +1,134 @@
+import {
+  ASTNode,
+  DefinitionNode,
+  DocumentNode,
+  Kind,
+  NameNode,
+  print,
+  visit,
+} from "graphql";
+import { createHash } from "@apollo/utils.createhash";
+import { Schema } from "./definitions";
+
+function toDocument(schemaOrDocument: Schema | DocumentNode): DocumentNode {
+  return schemaOrDocument instanceof Schema ? schemaOrDocument.toAST() : schemaOrDocument;
+}
+
+/**
+ * Normalizes the whitespace of a description: leading and trailing whitespace is removed and any other sequence of
+ * whitespace (including line breaks) is replaced by a single space.
+ */
+export function normalizeDescription(description: string): string {
+  return description.trim().replace(/\s+/g, ' ');
+}
+
+function normalizeDescriptions<T extends ASTNode>(node: T): T {
+  return visit(node, {
+    StringValue(value, key) {
+      // Descriptions are printed as block strings or not depending on how they were written, so we also normalize that.
+      return key === 'description' ? { ...value, value: normalizeDescription(value.value), block: false } : undefined;
+    },
+  });
+}
+
+function definitionName(definition: DefinitionNode): string {
+  return 'name' in definition && definition.name ? definition.name.value : '';
+}
+
+// Strings are compared by UTF-16 code units (rather than with `localeCompare`, which depends on the locale and ICU
+// data of the runtime), so that normalization, and thus `schemaHash`, is the same everywhere.
+function compareStrings(s1: string, s2: string): number {
+  return s1 === s2 ? 0 : s1 < s2 ? -1 : 1;
+}
+
+function compareDefinitions(d1: DefinitionNode, d2: DefinitionNode): number {
+  return compareStrings(definitionName(d1), definitionName(d2)) || compareStrings(d1.kind, d2.kind);
+}
+
+/**
+ * Whether the 2 provided schemas (or documents) are semantically equal, that is equal modulo the locations of their
+ * nodes, the order of their (top-level) definitions, and the whitespace in their descriptions.
+ *
+ * Note that the order of the elements within a definition (fields, arguments, ...) does matter for this method.
+ */
+export function semanticallyEqual(s1: Schema | DocumentNode, s2: Schema | DocumentNode): boolean {
+  const printDefinitions = (schemaOrDocument: Schema | DocumentNode) => normalizeDescriptions(toDocument(schemaOrDocument))
+    .definitions
+    .concat()
+    .sort(compareDefinitions)
+    .map((definition) => print(definition));
+
+  const defs1 = printDefinitions(s1);
+  const defs2 = printDefinitions(s2);
+  return defs1.length === defs2.length && defs1.every((def, i) => def === defs2[i]);
+}
+
+// The nodes whose lists of (named) sub-elements are sorted by `normalizeDocument`, with the keys of those lists.
+const SORTED_LISTS_BY_KIND: { [kind: string]: string[] } = {
+  [Kind.OBJECT_TYPE_DEFINITION]: ['interfaces', 'fields'],
+  [Kind.OBJECT_TYPE_EXTENSION]: ['interfaces', 'fields'],
+  [Kind.INTERFACE_TYPE_DEFINITION]: ['interfaces', 'fields'],
+  [Kind.INTERFACE_TYPE_EXTENSION]: ['interfaces', 'fields'],
+  [Kind.UNION_TYPE_DEFINITION]: ['types'],
+  [Kind.UNION_TYPE_EXTENSION]: ['types'],
+  [Kind.ENUM_TYPE_DEFINITION]: ['values'],
+  [Kind.ENUM_TYPE_EXTENSION]: ['values'],
+  [Kind.INPUT_OBJECT_TYPE_DEFINITION]: ['fields'],
+  [Kind.INPUT_OBJECT_TYPE_EXTENSION]: ['fields'],
+  [Kind.FIELD_DEFINITION]: ['arguments'],
+  [Kind.DIRECTIVE_DEFINITION]: ['arguments', 'locations'],
+};
+
+// The sorted lists either contain nodes having a name, or names (for directive locations).
+type SortedNode = { name: NameNode } | NameNode;
+
+function sortKey(node: SortedNode): string {
+  return 'value' in node ? node.value : node.name.value;
+}
+
+/**
+ * Returns a canonical form of the provided schema (or document), useful to hash or diff schema snapshots. In the returned
+ * document:
+ * - top-level definitions are sorted by name (and then by kind, for a type and its extensions);
+ * - fields, arguments, input fields, enum values, union members, implemented interfaces and directive locations are
+ *   sorted by name;
+ * - the whitespace of descriptions is normalized (see `normalizeDescription`) and locations are removed.
+ *
+ * Operations and fragments are kept as is (apart from their position in the document), as the order of their selections matters.
+ */
+export function normalizeDocument(schemaOrDocument: Schema | DocumentNode): DocumentNode {
+  const normalized = visit(normalizeDescriptions(toDocument(schemaOrDocument)), {
+    enter(node) {
+      const { loc, ...withoutLocation } = node;
+      return loc ? withoutLocation : undefined;
+    },
+    leave(node) {
+      const keys = SORTED_LISTS_BY_KIND[node.kind];
+      if (!keys) {
+        return undefined;
+      }
+      const sorted: { [key: string]: unknown } = { ...node };
+      for (const key of keys) {
+        const list = (node as any)[key];
+        if (list) {
+          sorted[key] = list.concat().sort((n1: SortedNode, n2: SortedNode) => compareStrings(sortKey(n1), sortKey(n2)));
+        }
+      }
+      return sorted as unknown as ASTNode;
+    },
+  });
+  return {
+    ...normalized,
+    definitions: normalized.definitions.concat().sort(compareDefinitions),
+  };
+}
+
+/**
+ * A deterministic digest (the hex-encoded sha256) of the normalized form of the provided schema (see `normalizeDocument`).
+ *
+ * Schemas that only differ by the order of their elements, the whitespace of their descriptions or the locations of their
+ * nodes have the same hash, so this can be used to detect drift between schemas without computing a full diff.
+ */
+export function schemaHash(schemaOrDocument: Schema | DocumentNode): string {
+  return createHash('sha256').update(print(normalizeDocument(schemaOrDocument))).digest('hex');
+}
@@ -0,0 +1,179 @@
+import {
+  DocumentNode,
+  GraphQLError,
+  OperationDefinitionNode,
+  print,
+  TypeInfo,
+  validate,
+  ValueNode,
+  ValuesOfCorrectTypeRule,
+  VariableNode,
+  VariablesInAllowedPositionRule,
+  visit,
+  visitWithTypeInfo,
+} from "graphql";
+import {
+  isListType,
+  isNonNullType,
+  Schema,
+  Variable,
+} from "./definitions";
+import { extractOperationDocument, Field, Operation, SelectionSet } from "./operations";
+
+/**
+ * The depth of the provided operation, that is the maximum number of nested fields in the operation (so `{ a }` has depth
+ * 1 and `{ a { b } }` has depth 2). Fragments (named or inline) do not add to the depth.
+ */
+export function operationDepth(operation: Operation): number {
+  return selectionSetDepth(operation.selectionSet);
+}
+
+function selectionSetDepth(selectionSet: SelectionSet): number {
+  let depth = 0;
+  for (const selection of selectionSet.selections()) {
+    const subSelectionSet = selection.selectionSet;
+    const subDepth = subSelectionSet ? selectionSetDepth(subSelectionSet) : 0;
+    depth = Math.max(depth, selection.kind === 'FieldSelection' ? subDepth + 1 : subDepth);
+  }
+  return depth;
+}
+
+export type ComplexityOptions = {
+  // The cost of a field, unless it has a specific cost in `fieldCosts`. Defaults to 1.
+  defaultFieldCost?: number,
+  // Specific field costs, by field coordinate (for instance, `{ 'Query.search': 10 }`).
+  fieldCosts?: { [coordinate: string]: number },
+  // The names of the arguments giving the (maximum) size of the list returned by a field. Defaults to `first`, `last` and
+  // `limit`.
+  listSizeArguments?: readonly string[],
+  // The assumed size of the lists returned by list fields when none of the `listSizeArguments` is provided (or when its
+  // value is not a non-negative integer). Defaults to 1.
+  defaultListSize?: number,
+  // The values of the variables of the operation, used when a list size argument is a variable. Variables not provided
+  // here use their default value in the operation, if any.
+  variables?: Record<string, any>,
+}
+
+const DEFAULT_LIST_SIZE_ARGUMENTS = ['first', 'last', 'limit'];
+
+/**
+ * A complexity score for the provided operation, which can be used to reject operations that are too expensive.
+ *
+ * The complexity of a field is its cost (see `ComplexityOptions`) plus the complexity of its sub-selection, the latter
+ * multiplied by the size of the list returned by the field (if it returns a list). The complexity of an operation is the
+ * sum of the complexity of its fields. `__typename` fields have no cost.
+ *
+ * Note that the selections of all the fragments are counted, even when their type conditions cannot apply together, so the
+ * score is an upper bound.
+ */
+export function operationComplexity(operation: Operation, options: ComplexityOptions = {}): number {
+  const variables = { ...operation.collectDefaultedVariableValues(), ...options.variables };
+  const listSizeArguments = options.listSizeArguments ?? DEFAULT_LIST_SIZE_ARGUMENTS;
+
+  const fieldCost = (field: Field): number => {
+    if (field.name === '__typename') {
+      return 0;
+    }
+    return options.fieldCosts?.[field.definition.coordinate] ?? options.defaultFieldCost ?? 1;
+  };
+
+  const listSize = (field: Field): number => {
+    const type = isNonNullType(field.definition.type!) ? field.definition.type.ofType : field.definition.type!;
+    if (!isListType(type)) {
+      return 1;
+    }
+    for (const argName of listSizeArguments) {
+      let value = field.argumentValue(argName);
+      if (value instanceof Variable) {
+        value = variables[value.name];
+      }
+      if (typeof value === 'number') {
+        // Negative (or fractional) sizes would lower the complexity of the sub-selection, so they are not trusted.
+        return Number.isInteger(value) && value >= 0 ? value : options.defaultListSize ?? 1;
+      }
+    }
+    return options.defaultListSize ?? 1;
+  };
+
+  const selectionSetComplexity = (selectionSet: SelectionSet): number => {
+    let complexity = 0;
+    for (const selection of selectionSet.selections()) {
+      const subSelectionSet = selection.selectionSet;
+      const subComplexity = subSelectionSet ? selectionSetComplexity(subSelectionSet) : 0;
+      if (selection.kind === 'FieldSelection') {
+        complexity += fieldCost(selection.element) + listSize(selection.element) * subComplexity;
+      } else {
+        complexity += subComplexity;
+      }
+    }
+    return complexity;
+  };
+
+  return selectionSetComplexity(operation.selectionSet);
+}
+
+export type VariableUsage = {
+  name: string,
+  // The type expected at the position the variable is used at (printed, like `[Int!]`). A variable can only be used at
+  // that position if its type is the same or more restrictive (or if either the variable or the position has a default
+  // value and the types only differ by nullability).
+  expectedType: string,
+  node: VariableNode,
+}
+
+export type VariablesAnalysis = {
+  // The variables defined by the operation, in definition order, with their usages.
+  variables: {
+    name: string,
+    type: string,
+    defaultValue?: ValueNode,
+    usages: VariableUsage[],
+  }[],
+  // The names of the defined variables that are not used by the operation.
+  unused: string[],
+  // The usages of variables that are not defined by the operation.
+  undefinedUsages: VariableUsage[],
+  // The errors for variables used in positions incompatible with their type, and for invalid values (including the
+  // default values of variables).
+  errors: GraphQLError[],
+}
+
+/**
+ * Analyzes the variables of the provided operation (see `extractOperationDocument` for how the operation is selected
+ * in the document). The usages of variables include those in the fragments used by the operation.
+ */
+export function analyzeVariables(schema: Schema, document: DocumentNode, operationName?: string): VariablesAnalysis {
+  const operationDocument = extractOperationDocument(document, operationName);
+  const operation = operationDocument.definitions[0] as OperationDefinitionNode;
+  const variables: VariablesAnalysis['variables'] = (operation.variableDefinitions ?? []).map((definition) => ({
+    name: definition.variable.name.value,
+    type: print(definition.type),
+    defaultValue: definition.defaultValue,
+    usages: [],
+  }));
+  const undefinedUsages: VariableUsage[] = [];
+
+  const graphQLSchema = schema.toGraphQLJSSchema();
+  const typeInfo = new TypeInfo(graphQLSchema);
+  visit(operationDocument, visitWithTypeInfo(typeInfo, {
+    // The variables in definitions are not usages.
+    VariableDefinition: () => false,
+    Variable(node) {
+      const inputType = typeInfo.getInputType();
+      const usage = { name: node.name.value, expectedType: inputType ? inputType.toString() : 'unknown', node };
+      const variable = variables.find((v) => v.name === usage.name);
+      if (variable) {
+        variable.usages.push(usage);
+      } else {
+        undefinedUsages.push(usage);
+      }
+    },
+  }));
+
+  return {
+    variables,
+    unused: variables.filter((v) => v.usages.length === 0).map((v) => v.name),
+    undefinedUsages,
+    errors: validate(graphQLSchema, operationDocument, [VariablesInAllowedPositionRule, ValuesOfCorrectTypeRule]).concat(),
+  };
+}
@@ -0,0 +1,84 @@
+import {
+  ASTNode,
+  DirectiveNode,
+  DocumentNode,
+  FieldNode,
+  Kind,
+  print,
+  visit,
+} from "graphql";
+import { extractOperationDocument } from "./operations";
+
+function sortKey(node: ASTNode): string | undefined {
+  if ('name' in node && node.name) {
+    return node.name.value;
+  }
+  return node.kind === Kind.VARIABLE_DEFINITION ? node.variable.name.value : undefined;
+}
+
+// Sorts by kind, then by name, keeping the original order of nodes with no name (inline fragments). Note that this
+// compares strings by code units (not `localeCompare`), to match the signatures computed by other tools.
+function sortNodes<T extends ASTNode>(nodes: readonly T[] | undefined): T[] | undefined {
+  if (!nodes) {
+    return undefined;
+  }
+  const compare = (s1: string | undefined, s2: string | undefined) => s1 === s2 ? 0 : (s1 ?? '') < (s2 ?? '') ? -1 : 1;
+  return nodes.concat().sort((n1, n2) => compare(n1.kind, n2.kind) || compare(sortKey(n1), sortKey(n2)));
+}
+
+/**
+ * The signature of the provided operation, as used by Apollo usage reporting to aggregate the metrics of operations having
+ * the same shape. The signature is computed from the operation (and the fragments it uses) by:
+ * - replacing numbers by `0`, strings by `""`, and lists and input objects by `[]` and `{}` respectively (so that no
+ *   sensitive data ends up in the signature);
+ * - removing aliases;
+ * - sorting definitions, selections, arguments and variable definitions, as well as the directives of fragments, by kind
+ *   and name;
+ * - printing the result with as little whitespace as possible.
+ *
+ * See `extractOperationDocument` for how the operation is selected in the document.
+ */
+export function operationSignature(document: DocumentNode, operationName?: string): string {
+  const signatureDocument = visit(extractOperationDocument(document, operationName), {
+    IntValue: (node) => ({ ...node, value: '0' }),
+    FloatValue: (node) => ({ ...node, value: '0' }),
+    StringValue: (node) => ({ ...node, value: '', block: false }),
+    ListValue: (node) => ({ ...node, values: [] }),
+    ObjectValue: (node) => ({ ...node, fields: [] }),
+    Field: {
+      leave: (node): FieldNode => ({
+        ...node,
+        alias: undefined,
+        arguments: sortNodes(node.arguments),
+      }),
+    },
+    Directive: {
+      leave: (node): DirectiveNode => ({ ...node, arguments: sortNodes(node.arguments) }),
+    },
+    SelectionSet: {
+      leave: (node) => ({ ...node, selections: sortNodes(node.selections)! }),
+    },
+    FragmentSpread: {
+      leave: (node) => ({ ...node, directives: sortNodes(node.directives) }),
+    },
+    InlineFragment: {
+      leave: (node) => ({ ...node, directives: sortNodes(node.directives) }),
+    },
+    FragmentDefinition: {
+      leave: (node) => ({ ...node, directives: sortNodes(node.directives), variableDefinitions: sortNodes(node.variableDefinitions) }),
+    },
+    OperationDefinition: {
+      leave: (node) => ({ ...node, variableDefinitions: sortNodes(node.variableDefinitions) }),
+    },
+    Document: {
+      leave: (node) => ({ ...node, definitions: sortNodes(node.definitions)! }),
+    },
+  });
+
+  // As all strings have been emptied, we can remove any whitespace that is not between 2 names (or keywords) without
+  // risking to modify a string value.
+  return print(signatureDocument)
+    .replace(/\s+/g, ' ')
+    .replace(/([^_a-zA-Z0-9]) /g, '$1')
+    .replace(/ ([^_a-zA-Z0-9])/g, '$1');
+}
@@ -15,6 +15,7 @@ import {
   SelectionSetNode,
   OperationTypeNode,
   NameNode,
+  visit,
 } from "graphql";
 import {
   baseType,
@@ -57,6 +58,8 @@ import { isSubtype, sameType, typesCanBeMerged } from "./types";
 import { assert, mapKeys, mapValues, MapWithCachedArrays, MultiMap, SetMultiMap } from "./utils";
 import { argumentsEquals, argumentsFromAST, isValidValue, valueToAST, valueToString } from "./values";
 import { v1 as uuidv1 } from 'uuid';
+import { parseWithLimits, ParserOptions } from "./parsing";
+import { suggestionList, withSuggestions } from "./suggestions";
 
 function validate(condition: any, message: () => string, sourceAST?: ASTNode): asserts condition {
   if (!condition) {
@@ -879,6 +882,71 @@ function computeFragmentsToKeep(
   return toExpand.size === 0 ? fragments : fragments.filter((f) => !toExpand.has(f.name));
 }
 
+// Evaluates the @skip/@include of the provided selections (which should not have fragment spreads), returning the
+// selections that are included, without their conditions. Note that the returned selection set may be empty.
+function evaluateConditionsInSelections(selectionSet: SelectionSet, values: Record<string, any>): SelectionSet {
+  return selectionSet.lazyMap((selection) => {
+    const element = selection.element;
+    let isIncluded = true;
+    const remainingDirectives = element.appliedDirectives.filter((directive) => {
+      if (!isConditionalDirective(directive)) {
+        return true;
+      }
+      let condition = directive.arguments()['if'];
+      if (condition instanceof Variable) {
+        const variable = condition;
+        condition = values[variable.name];
+        validate(typeof condition === 'boolean', () => `Missing or non-boolean value for variable "${variable}" used in @${directive.name}`);
+      }
+      isIncluded = isIncluded && (directive.name === 'skip' ? !condition : condition);
+      return false;
+    });
+    if (!isIncluded) {
+      return undefined;
+    }
+
+    const updatedElement = remainingDirectives.length === element.appliedDirectives.length
+      ? element
+      : element.withUpdatedDirectives(remainingDirectives);
+    if (!selection.selectionSet) {
+      return updatedElement === element ? selection : selectionOfElement(updatedElement);
+    }
+
+    if (updatedElement.kind === 'Field') {
+      const updatedSelectionSet = evaluateConditions(selection.selectionSet, values);
+      return updatedElement === element && updatedSelectionSet === selection.selectionSet
+        ? selection
+        : selectionOfElement(updatedElement, updatedSelectionSet);
+    }
+
+    // Fragments with no remaining selections can be removed, and the ones that no longer have a type condition nor
+    // directives are just replaced by their selections.
+    const updatedSelectionSet = evaluateConditionsInSelections(selection.selectionSet, values);
+    if (updatedSelectionSet.isEmpty()) {
+      return undefined;
+    }
+    if (!updatedElement.typeCondition && updatedElement.appliedDirectives.length === 0) {
+      return updatedSelectionSet;
+    }
+    return updatedElement === element && updatedSelectionSet === selection.selectionSet
+      ? selection
+      : selectionOfElement(updatedElement, updatedSelectionSet);
+  });
+}
+
+function evaluateConditions(selectionSet: SelectionSet, values: Record<string, any>): SelectionSet {
+  const evaluated = evaluateConditionsInSelections(selectionSet, values);
+  if (!evaluated.isEmpty()) {
+    return evaluated;
+  }
+  // If every sub-selection of a field is skipped, the field still resolves to an (empty) object. But as we cannot
+  // have empty selection sets, we use a `__typename @include(if: false)`, which is valid and preserves that behaviour
+  // (see `Operation.withEvaluatedConditions`).
+  const parentType = selectionSet.parentType;
+  const typename = new Field(parentType.typenameField()!, undefined, [new Directive<Field>('include', { if: false })]);
+  return selectionSetOf(parentType, selectionOfElement(typename));
+}
+
 export class Operation {
   constructor(
     readonly schema: Schema,
@@ -1047,6 +1115,46 @@ export class Operation {
     };
   }
 
+  /**
+   * Returns this operation with its `@skip` and `@include` conditions evaluated using the provided variable values
+   * (variables with no provided value use their default value, if any): the selections that are excluded are removed,
+   * and the conditions are removed from the ones that are included. Fragments are expanded in the returned operation,
+   * and the variables that are no longer used are removed from its variable definitions, so it remains valid.
+   *
+   * When all the sub-selections of a field are excluded, the field is kept (it still resolves to an object, even if empty)
+   * with a single `__typename @include(if: false)` sub-selection, as GraphQL does not allow empty selection sets. That
+   * selection is the only condition left in the returned operation.
+   *
+   * This throws if a variable used in a condition has neither a boolean value nor a default value.
+   */
+  withEvaluatedConditions(variableValues?: Record<string, any>): Operation {
+    const values = { ...this.collectDefaultedVariableValues(), ...variableValues };
+    const expanded = this.expandAllFragments();
+    const selectionSet = evaluateConditions(expanded.selectionSet, values);
+
+    const collector = new VariableCollector();
+    selectionSet.collectVariables(collector);
+    for (const directive of this.directives ?? []) {
+      collector.collectInArguments(directive.arguments());
+    }
+    const usedVariables = new Set(collector.variables().map((v) => v.name));
+    const variableDefinitions = new VariableDefinitions();
+    for (const definition of this.variableDefinitions.definitions()) {
+      if (usedVariables.has(definition.variable.name)) {
+        variableDefinitions.add(definition);
+      }
+    }
+    return new Operation(
+      this.schema,
+      this.rootKind,
+      selectionSet,
+      variableDefinitions,
+      undefined,
+      this.name,
+      this.directives,
+    );
+  }
+
   collectDefaultedVariableValues(): Record<string, any> {
     const defaultedVariableValues: Record<string, any> = {};
     for (const { variable, defaultValue } of this.variableDefinitions.definitions()) {
@@ -3796,7 +3904,14 @@ function selectionOfNode(
   switch (node.kind) {
     case Kind.FIELD:
       const definition: FieldDefinition<any> | undefined  = fieldAccessor(parentType, node.name.value);
-      validate(definition, () => `Cannot query field "${node.name.value}" on type "${parentType}".`, parentType.sourceAST);
+      validate(
+        definition,
+        () => withSuggestions(
+          `Cannot query field "${node.name.value}" on type "${parentType}".`,
+          suggestionList(node.name.value, isUnionType(parentType) ? [] : parentType.fields().map((f) => f.name)),
+        ),
+        parentType.sourceAST,
+      );
       const type = baseType(definition.type!);
       const selectionSet = node.selectionSet
         ? selectionSetOfNode(type as CompositeType, node.selectionSet, variableDefinitions, fragments, fieldAccessor)
@@ -3808,7 +3923,12 @@ function selectionOfNode(
       );
       break;
     case Kind.INLINE_FRAGMENT:
-      const element = new FragmentElement(parentType, node.typeCondition?.name.value, directives);
+      const typeConditionName = node.typeCondition?.name.value;
+      if (typeConditionName && !parentType.schema().type(typeConditionName)) {
+        const suggestions = suggestionList(typeConditionName, parentType.schema().types().map((t) => t.name));
+        throw ERRORS.INVALID_GRAPHQL.err(withSuggestions(`Unknown type "${typeConditionName}" for inline fragment`, suggestions), { nodes: node });
+      }
+      const element = new FragmentElement(parentType, typeConditionName, directives);
       selection = new InlineFragmentSelection(
         element,
         selectionSetOfNode(element.typeCondition ? element.typeCondition : element.parentType, node.selectionSet, variableDefinitions, fragments, fieldAccessor),
@@ -3853,7 +3973,8 @@ export function operationFromDocument(
         const typeName = definition.typeCondition.name.value;
         const typeCondition = schema.type(typeName);
         if (!typeCondition) {
-          throw ERRORS.INVALID_GRAPHQL.err(`Unknown type "${typeName}" for fragment "${name}"`, { nodes: definition });
+          const suggestions = suggestionList(typeName, schema.types().map((t) => t.name));
+          throw ERRORS.INVALID_GRAPHQL.err(withSuggestions(`Unknown type "${typeName}" for fragment "${name}"`, suggestions), { nodes: definition });
         }
         if (!isCompositeType(typeCondition)) {
           throw ERRORS.INVALID_GRAPHQL.err(`Invalid fragment "${name}" on non-composite type "${typeName}"`, { nodes: definition });
@@ -3882,6 +4003,53 @@ export function operationFromDocument(
   return operationFromAST({schema, operation, operation_directives, variableDefinitions, fragments, validateInput: options?.validate});
 }
 
+/**
+ * Extracts the operation named `operationName` from the provided document, along with only the fragments that this
+ * operation (transitively) uses, and returns them as a new document.
+ *
+ * As in the GraphQL spec, if no name is provided, the document must contain exactly one operation (which may be
+ * anonymous).
+ */
+export function extractOperationDocument(document: DocumentNode, operationName?: string): DocumentNode {
+  const operations = document.definitions.filter((def): def is OperationDefinitionNode => def.kind === Kind.OPERATION_DEFINITION);
+  let operation: OperationDefinitionNode | undefined;
+  if (operationName === undefined) {
+    validate(operations.length > 0, () => 'No operation found in provided document.');
+    validate(operations.length === 1, () => 'Must provide operation name if query contains multiple operations.');
+    operation = operations[0];
+  } else {
+    operation = operations.find((op) => op.name?.value === operationName);
+    validate(operation, () => `Unknown operation named "${operationName}"`);
+  }
+
+  const fragments = new Map<string, FragmentDefinitionNode>();
+  for (const definition of document.definitions) {
+    if (definition.kind === Kind.FRAGMENT_DEFINITION) {
+      fragments.set(definition.name.value, definition);
+    }
+  }
+  const usedFragments = new Set<string>();
+  const toVisit: ASTNode[] = [operation];
+  let node: ASTNode | undefined;
+  while ((node = toVisit.pop()) !== undefined) {
+    visit(node, {
+      FragmentSpread(spread) {
+        const fragment = fragments.get(spread.name.value);
+        if (fragment && !usedFragments.has(fragment.name.value)) {
+          usedFragments.add(fragment.name.value);
+          toVisit.push(fragment);
+        }
+      },
+    });
+  }
+
+  return {
+    kind: Kind.DOCUMENT,
+    // We keep the fragments in their original order.
+    definitions: [operation, ...[...fragments.values()].filter((fragment) => usedFragments.has(fragment.name.value))],
+  };
+}
+
 function operationFromAST({
   schema,
   operation,
@@ -3923,9 +4091,10 @@ export function parseOperation(
   options?: {
     operationName?: string,
     validate?: boolean,
+    parserOptions?: ParserOptions,
   },
 ): Operation {
-  return operationFromDocument(schema, parse(operation), options);
+  return operationFromDocument(schema, parseWithLimits(operation, options?.parserOptions), options);
 }
 
 export function parseSelectionSet({
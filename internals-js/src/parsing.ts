@@ -0,0 +1,194 @@
+import {
+  ASTNode,
+  concatAST,
+  DocumentNode,
+  getLocation,
+  GraphQLError,
+  Kind,
+  Lexer,
+  parse,
+  Source,
+  syntaxError,
+  TokenKind,
+  visit,
+} from "graphql";
+import { ERRORS } from "./error";
+
+/**
+ * Limits applied when parsing documents (schemas or operations), so that servers exposed to untrusted input can bound
+ * the resources spent on pathological documents. All limits are optional and unbounded by default.
+ */
+export type ParserOptions = {
+  // The maximum size, in bytes (of the UTF-8 encoding), of the parsed document.
+  maxBytes?: number,
+  // The maximum number of (lexical) tokens of the parsed document.
+  maxTokens?: number,
+  // The maximum nesting depth of the parsed document, where each selection set, list or object value and list type
+  // opens a new level of nesting.
+  maxDepth?: number,
+}
+
+const NESTING_KINDS: readonly Kind[] = [Kind.SELECTION_SET, Kind.LIST, Kind.OBJECT, Kind.LIST_TYPE];
+
+/**
+ * Parses the provided source, rejecting it if it exceeds any of the provided limits.
+ *
+ * The size limit is checked before any parsing happens and the token limit aborts parsing as soon as it is reached, so
+ * rejecting oversized documents is cheap. The depth limit is checked on the parsed document, but documents that are
+ * much too deep are rejected by a (non-recursive) scan of their tokens before parsing, so that they cannot exhaust
+ * the stack of the (recursive) parser.
+ */
+export function parseWithLimits(source: string | Source, options?: ParserOptions): DocumentNode {
+  const body = typeof source === 'string' ? source : source.body;
+  if (options?.maxBytes !== undefined) {
+    const size = new TextEncoder().encode(body).length;
+    if (size > options.maxBytes) {
+      throw ERRORS.INVALID_GRAPHQL.err(`Document is ${size} bytes, which exceeds the maximum of ${options.maxBytes} bytes.`);
+    }
+  }
+
+  if (options?.maxDepth !== undefined) {
+    checkBracketDepth(typeof source === 'string' ? new Source(source) : source, options.maxDepth, options.maxTokens);
+  }
+
+  const document = parseWithEmptyBracesHint(source, options?.maxTokens);
+
+  if (options?.maxDepth !== undefined) {
+    const depth = nestingDepth(document);
+    if (depth > options.maxDepth) {
+      throw ERRORS.INVALID_GRAPHQL.err(`Document has a nesting depth of ${depth}, which exceeds the maximum of ${options.maxDepth}.`);
+    }
+  }
+  return document;
+}
+
+// Each level of nesting (as defined by `nestingDepth`) is opened by a `{` or `[`, and at most 3 brackets on any path
+// do not open such a level: the body of a type definition, the parentheses of argument definitions, and the parentheses
+// of the arguments of a directive applied to an argument definition (like in `type T { f(a: Int @d(x: 1)): Int }`).
+// Directive arguments only contain values, so no other parentheses nor bodies. So a bracket depth above
+// `maxDepth + 3` implies a nesting depth above `maxDepth`, and below that bound, the parser recursion is bounded.
+const MAX_NON_NESTING_BRACKETS = 3;
+
+function checkBracketDepth(source: Source, maxDepth: number, maxTokens: number | undefined) {
+  const lexer = new Lexer(source);
+  let depth = 0;
+  let tokens = 0;
+  // If there are more tokens than allowed, the parser will abort before reading past that point, so we can stop as well.
+  while (lexer.advance().kind !== TokenKind.EOF && (maxTokens === undefined || ++tokens <= maxTokens)) {
+    switch (lexer.token.kind) {
+      case TokenKind.BRACE_L:
+      case TokenKind.BRACKET_L:
+      case TokenKind.PAREN_L:
+        if (++depth > maxDepth + MAX_NON_NESTING_BRACKETS) {
+          throw ERRORS.INVALID_GRAPHQL.err(`Document has a nesting depth exceeding the maximum of ${maxDepth}.`);
+        }
+        break;
+      case TokenKind.BRACE_R:
+      case TokenKind.BRACKET_R:
+      case TokenKind.PAREN_R:
+        depth--;
+        break;
+    }
+  }
+}
+
+/**
+ * Parses the provided source, replacing the generic error graphql-js reports on empty braces (like in `type Foo {}`), which
+ * easily confuses newcomers, by a more targeted one.
+ */
+function parseWithEmptyBracesHint(source: string | Source, maxTokens: number | undefined): DocumentNode {
+  try {
+    return parse(source, { maxTokens });
+  } catch (e) {
+    if (e instanceof GraphQLError && e.source && e.positions?.length === 1 && e.message === 'Syntax Error: Expected Name, found "}".') {
+      const position = e.positions[0];
+      if (e.source.body.slice(0, position).trimEnd().endsWith('{')) {
+        throw syntaxError(e.source, position, 'Braces cannot be empty: either remove them or add at least one field (or enum value) inside them.');
+      }
+    }
+    throw e;
+  }
+}
+
+/**
+ * The maximum nesting depth of the provided node (see `ParserOptions.maxDepth` for what counts as nesting).
+ */
+export function nestingDepth(node: ASTNode): number {
+  let depth = 0;
+  let maxDepth = 0;
+  visit(node, {
+    enter(n) {
+      if (NESTING_KINDS.includes(n.kind)) {
+        depth++;
+        maxDepth = Math.max(maxDepth, depth);
+      }
+    },
+    leave(n) {
+      if (NESTING_KINDS.includes(n.kind)) {
+        depth--;
+      }
+    },
+  });
+  return maxDepth;
+}
+
+/**
+ * Parses each of the provided sources (typically one per file) and concatenates their definitions into a single document.
+ *
+ * The nodes of the returned document keep a reference to the source they were parsed from, so errors raised on those nodes
+ * point to the proper file (using the `name` of the sources, see also `sourceLocation`). The provided limits apply to
+ * each source separately.
+ */
+export function parseSources(sources: readonly Source[], options?: ParserOptions): DocumentNode {
+  return concatAST(sources.map((source) => parseWithLimits(source, options)));
+}
+
+/**
+ * The location of the provided node in its source, as `<source name>:<line>:<column>` (for instance `accounts.graphql:14:3`),
+ * or `undefined` if the node has no location.
+ */
+export function sourceLocation(node: ASTNode): string | undefined {
+  const loc = node.loc;
+  if (!loc) {
+    return undefined;
+  }
+  const { line, column } = getLocation(loc.source, loc.start);
+  return `${loc.source.name}:${line}:${column}`;
+}
+
+function offsetOf(body: string, line: number, column: number): number {
+  let offset = 0;
+  for (let currentLine = 1; currentLine < line; currentLine++) {
+    const lineBreak = body.slice(offset).search(/\r\n|[\n\r]/);
+    if (lineBreak < 0) {
+      return body.length;
+    }
+    offset += lineBreak + (body.startsWith('\r\n', offset + lineBreak) ? 2 : 1);
+  }
+  return offset + column - 1;
+}
+
+/**
+ * Returns the most specific node of the provided document (or sub-tree) that covers the provided position, or `undefined`
+ * if no node covers it (or if the nodes have no location).
+ *
+ * The position is either a character offset in the source, or a 1-based line and column (as in the locations of errors).
+ */
+export function nodeAtPosition(root: ASTNode, position: number | { line: number, column: number }): ASTNode | undefined {
+  if (!root.loc) {
+    return undefined;
+  }
+  const offset = typeof position === 'number' ? position : offsetOf(root.loc.source.body, position.line, position.column);
+  let found: ASTNode | undefined = undefined;
+  visit(root, {
+    enter(node) {
+      if (!node.loc || offset < node.loc.start || offset >= node.loc.end) {
+        // No children of a node can cover the position if the node itself does not.
+        return false;
+      }
+      found = node;
+      return undefined;
+    },
+  });
+  return found;
+}
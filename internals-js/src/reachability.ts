@@ -0,0 +1,78 @@
+import {
+  baseType,
+  DirectiveDefinition,
+  isExecutableDirectiveLocation,
+  NamedType,
+  Schema,
+} from "./definitions";
+
+export type UnreachableElements = {
+  // The types that cannot be reached from any of the root types of the schema, nor from the arguments of the directives
+  // that are kept (see below). All the fields, arguments and input fields of those types are unreachable too.
+  types: NamedType[],
+  // The directives that cannot be used in operations (they have no executable location) and that are not applied
+  // anywhere in the schema.
+  directives: DirectiveDefinition[],
+}
+
+/**
+ * Computes the elements of the provided schema that can never be referenced, either by operations or by the rest of the
+ * schema. Those elements could be removed without any impact, which can be useful to prune large legacy schemas.
+ *
+ * A type is reachable if it is a root type, or the type of a field, argument or input field of a reachable type, or a
+ * member of a reachable union, or an implementation (or implemented interface) of a reachable interface or object type.
+ * Built-in elements and the elements of the core features linked by the schema are never reported.
+ */
+export function unreachableElements(schema: Schema): UnreachableElements {
+  const isFeatureElement = (element: NamedType | DirectiveDefinition) => !!schema.coreFeatures?.sourceFeature(element);
+
+  const directives = schema.directives().filter((directive) =>
+    !isFeatureElement(directive)
+    && !directive.locations.some((loc) => isExecutableDirectiveLocation(loc))
+    && directive.applications().length === 0
+  );
+
+  const reachable = new Set<string>();
+  const toVisit: NamedType[] = [];
+  const reach = (type: NamedType) => {
+    if (!reachable.has(type.name)) {
+      reachable.add(type.name);
+      toVisit.push(type);
+    }
+  };
+
+  schema.schemaDefinition.roots().forEach((root) => reach(root.type));
+  for (const directive of schema.directives()) {
+    if (!directives.includes(directive)) {
+      directive.arguments().forEach((arg) => reach(baseType(arg.type!)));
+    }
+  }
+
+  let type: NamedType | undefined;
+  while ((type = toVisit.pop()) !== undefined) {
+    switch (type.kind) {
+      case 'ObjectType':
+      case 'InterfaceType':
+        type.interfaces().forEach(reach);
+        if (type.kind === 'InterfaceType') {
+          type.allImplementations().forEach(reach);
+        }
+        for (const field of type.fields()) {
+          reach(baseType(field.type!));
+          field.arguments().forEach((arg) => reach(baseType(arg.type!)));
+        }
+        break;
+      case 'UnionType':
+        type.types().forEach(reach);
+        break;
+      case 'InputObjectType':
+        type.fields().forEach((field) => reach(baseType(field.type!)));
+        break;
+    }
+  }
+
+  return {
+    types: schema.types().filter((t) => !reachable.has(t.name) && !isFeatureElement(t)),
+    directives,
+  };
+}
@@ -0,0 +1,75 @@
+import {
+  DocumentNode,
+  getNamedType,
+  GraphQLType,
+  TypeInfo,
+  visit,
+  visitWithTypeInfo,
+} from "graphql";
+import { Schema } from "./definitions";
+
+export type SchemaCoverage = {
+  // The number of usages of each field of the object types and interfaces of the schema, by field coordinate. Fields are
+  // counted on the type they are queried on, so a field queried on an interface is counted on the interface field, not on
+  // the fields of the implementations.
+  fieldUsages: Map<string, number>,
+  // The coordinates of the fields of object types and interfaces that are never queried.
+  unusedFields: string[],
+  // The names of the types that are never referenced by the operations, neither as the type of a queried field or of a
+  // provided argument or input field, nor as a fragment type condition.
+  unusedTypes: string[],
+}
+
+/**
+ * Computes which elements of the provided schema are exercised by the provided operations (typically, a corpus of the
+ * operations sent by clients), which is useful to decide whether elements can be safely deprecated and removed.
+ *
+ * Operations are expected to be valid against the schema: elements that are not found in the schema are ignored.
+ * Built-in types, introspection fields and the types of the core features linked by the schema are not reported.
+ */
+export function schemaCoverage(schema: Schema, documents: readonly DocumentNode[]): SchemaCoverage {
+  const fieldUsages = new Map<string, number>();
+  const usedTypes = new Set<string>();
+  const types = schema.types().filter((type) => !schema.coreFeatures?.sourceFeature(type));
+  for (const type of types) {
+    if (type.kind === 'ObjectType' || type.kind === 'InterfaceType') {
+      type.fields().forEach((field) => fieldUsages.set(field.coordinate, 0));
+    }
+  }
+
+  const addType = (type: GraphQLType | null | undefined) => {
+    if (type) {
+      usedTypes.add(getNamedType(type).name);
+    }
+  };
+
+  const typeInfo = new TypeInfo(schema.toGraphQLJSSchema());
+  for (const document of documents) {
+    visit(document, visitWithTypeInfo(typeInfo, {
+      Field(node) {
+        const parentType = typeInfo.getParentType();
+        const type = typeInfo.getType();
+        if (parentType) {
+          usedTypes.add(parentType.name);
+          const coordinate = `${parentType.name}.${node.name.value}`;
+          const count = fieldUsages.get(coordinate);
+          if (count !== undefined) {
+            fieldUsages.set(coordinate, count + 1);
+          }
+        }
+        addType(type);
+      },
+      InlineFragment: () => addType(typeInfo.getType()),
+      FragmentDefinition: () => addType(typeInfo.getType()),
+      Argument: () => addType(typeInfo.getInputType()),
+      ObjectField: () => addType(typeInfo.getInputType()),
+      VariableDefinition: () => addType(typeInfo.getInputType()),
+    }));
+  }
+
+  return {
+    fieldUsages,
+    unusedFields: [...fieldUsages.entries()].filter(([, count]) => count === 0).map(([coordinate]) => coordinate),
+    unusedTypes: types.filter((type) => !usedTypes.has(type.name)).map((type) => type.name),
+  };
+}
@@ -0,0 +1,285 @@
+import {
+  BreakingChange,
+  BreakingChangeType,
+  DangerousChange,
+  DangerousChangeType,
+  findBreakingChanges,
+  findDangerousChanges,
+} from "graphql";
+import {
+  DirectiveDefinition,
+  FieldDefinition,
+  InputObjectType,
+  InterfaceType,
+  ObjectType,
+  Schema,
+} from "./definitions";
+import { assert } from "./utils";
+
+/**
+ * How a schema change impacts existing clients:
+ * - `BREAKING`: some previously valid operations may become invalid, or may get results they cannot handle.
+ * - `DANGEROUS`: previously valid operations remain valid, but may behave differently (for instance, a new enum value
+ *   may be returned to clients that do not expect it).
+ * - `SAFE`: existing operations are not impacted.
+ */
+export type SchemaChangeSeverity = 'BREAKING' | 'DANGEROUS' | 'SAFE';
+
+export type SchemaChangeKind =
+  'TYPE_ADDED'
+  | 'TYPE_REMOVED'
+  | 'TYPE_KIND_CHANGED'
+  | 'FIELD_ADDED'
+  | 'FIELD_REMOVED'
+  | 'FIELD_TYPE_CHANGED'
+  | 'INPUT_FIELD_ADDED'
+  | 'INPUT_FIELD_REMOVED'
+  | 'INPUT_FIELD_TYPE_CHANGED'
+  | 'ARGUMENT_ADDED'
+  | 'ARGUMENT_REMOVED'
+  | 'ARGUMENT_TYPE_CHANGED'
+  | 'ARGUMENT_DEFAULT_VALUE_CHANGED'
+  | 'ENUM_VALUE_ADDED'
+  | 'ENUM_VALUE_REMOVED'
+  | 'UNION_MEMBER_ADDED'
+  | 'UNION_MEMBER_REMOVED'
+  | 'IMPLEMENTED_INTERFACE_ADDED'
+  | 'IMPLEMENTED_INTERFACE_REMOVED'
+  | 'DIRECTIVE_ADDED'
+  | 'DIRECTIVE_REMOVED'
+  | 'DIRECTIVE_LOCATION_ADDED'
+  | 'DIRECTIVE_LOCATION_REMOVED'
+  | 'DIRECTIVE_REPEATABLE_REMOVED';
+
+export type SchemaChange = {
+  kind: SchemaChangeKind,
+  severity: SchemaChangeSeverity,
+  // The coordinate of the changed element (for additions, the coordinate of the added element).
+  coordinate: string,
+  message: string,
+}
+
+type GraphQLJSChangeParser = {
+  pattern: RegExp,
+  change: (match: RegExpMatchArray, oldSchema: Schema) => [SchemaChangeKind, string],
+}
+
+// graphql-js uses the same change types for the fields of output and input types.
+const fieldKind = (oldSchema: Schema, typeName: string, outputKind: SchemaChangeKind, inputKind: SchemaChangeKind) =>
+  oldSchema.type(typeName)?.kind === 'InputObjectType' ? inputKind : outputKind;
+
+// graphql-js only describes its changes with a message, so the coordinate of the changed element is extracted from it.
+const graphQLJSChangeParsers: Record<BreakingChangeType | DangerousChangeType, GraphQLJSChangeParser> = {
+  [BreakingChangeType.TYPE_REMOVED]: {
+    pattern: /^(\w+) was removed\.$/,
+    change: ([, type]) => ['TYPE_REMOVED', type],
+  },
+  [BreakingChangeType.TYPE_CHANGED_KIND]: {
+    pattern: /^(\w+) changed from /,
+    change: ([, type]) => ['TYPE_KIND_CHANGED', type],
+  },
+  [BreakingChangeType.TYPE_REMOVED_FROM_UNION]: {
+    pattern: /^\w+ was removed from union type (\w+)\.$/,
+    change: ([, union]) => ['UNION_MEMBER_REMOVED', union],
+  },
+  [BreakingChangeType.VALUE_REMOVED_FROM_ENUM]: {
+    pattern: /^(\w+) was removed from enum type (\w+)\.$/,
+    change: ([, value, type]) => ['ENUM_VALUE_REMOVED', `${type}.${value}`],
+  },
+  [BreakingChangeType.REQUIRED_INPUT_FIELD_ADDED]: {
+    pattern: /^A required field (\w+) on input type (\w+) was added\.$/,
+    change: ([, field, type]) => ['INPUT_FIELD_ADDED', `${type}.${field}`],
+  },
+  [BreakingChangeType.IMPLEMENTED_INTERFACE_REMOVED]: {
+    pattern: /^(\w+) no longer implements interface \w+\.$/,
+    change: ([, type]) => ['IMPLEMENTED_INTERFACE_REMOVED', type],
+  },
+  [BreakingChangeType.FIELD_REMOVED]: {
+    pattern: /^(\w+)\.(\w+) was removed\.$/,
+    change: ([, type, field], oldSchema) => [fieldKind(oldSchema, type, 'FIELD_REMOVED', 'INPUT_FIELD_REMOVED'), `${type}.${field}`],
+  },
+  [BreakingChangeType.FIELD_CHANGED_KIND]: {
+    pattern: /^(\w+)\.(\w+) changed type from /,
+    change: ([, type, field], oldSchema) => [fieldKind(oldSchema, type, 'FIELD_TYPE_CHANGED', 'INPUT_FIELD_TYPE_CHANGED'), `${type}.${field}`],
+  },
+  [BreakingChangeType.REQUIRED_ARG_ADDED]: {
+    pattern: /^A required arg (\w+) on (\w+)\.(\w+) was added\.$/,
+    change: ([, arg, type, field]) => ['ARGUMENT_ADDED', `${type}.${field}(${arg}:)`],
+  },
+  [BreakingChangeType.ARG_REMOVED]: {
+    pattern: /^(\w+)\.(\w+) arg (\w+) was removed\.$/,
+    change: ([, type, field, arg]) => ['ARGUMENT_REMOVED', `${type}.${field}(${arg}:)`],
+  },
+  [BreakingChangeType.ARG_CHANGED_KIND]: {
+    pattern: /^(\w+)\.(\w+) arg (\w+) has changed type from /,
+    change: ([, type, field, arg]) => ['ARGUMENT_TYPE_CHANGED', `${type}.${field}(${arg}:)`],
+  },
+  [BreakingChangeType.DIRECTIVE_REMOVED]: {
+    pattern: /^(\w+) was removed\.$/,
+    change: ([, directive]) => ['DIRECTIVE_REMOVED', `@${directive}`],
+  },
+  [BreakingChangeType.DIRECTIVE_ARG_REMOVED]: {
+    pattern: /^(\w+) was removed from (\w+)\.$/,
+    change: ([, arg, directive]) => ['ARGUMENT_REMOVED', `@${directive}(${arg}:)`],
+  },
+  [BreakingChangeType.REQUIRED_DIRECTIVE_ARG_ADDED]: {
+    pattern: /^A required arg (\w+) on directive (\w+) was added\.$/,
+    change: ([, arg, directive]) => ['ARGUMENT_ADDED', `@${directive}(${arg}:)`],
+  },
+  [BreakingChangeType.DIRECTIVE_REPEATABLE_REMOVED]: {
+    pattern: /^Repeatable flag was removed from (\w+)\.$/,
+    change: ([, directive]) => ['DIRECTIVE_REPEATABLE_REMOVED', `@${directive}`],
+  },
+  [BreakingChangeType.DIRECTIVE_LOCATION_REMOVED]: {
+    pattern: /^\w+ was removed from (\w+)\.$/,
+    change: ([, directive]) => ['DIRECTIVE_LOCATION_REMOVED', `@${directive}`],
+  },
+  [DangerousChangeType.VALUE_ADDED_TO_ENUM]: {
+    pattern: /^(\w+) was added to enum type (\w+)\.$/,
+    change: ([, value, type]) => ['ENUM_VALUE_ADDED', `${type}.${value}`],
+  },
+  [DangerousChangeType.TYPE_ADDED_TO_UNION]: {
+    pattern: /^\w+ was added to union type (\w+)\.$/,
+    change: ([, union]) => ['UNION_MEMBER_ADDED', union],
+  },
+  [DangerousChangeType.OPTIONAL_INPUT_FIELD_ADDED]: {
+    pattern: /^An optional field (\w+) on input type (\w+) was added\.$/,
+    change: ([, field, type]) => ['INPUT_FIELD_ADDED', `${type}.${field}`],
+  },
+  [DangerousChangeType.OPTIONAL_ARG_ADDED]: {
+    pattern: /^An optional arg (\w+) on (\w+)\.(\w+) was added\.$/,
+    change: ([, arg, type, field]) => ['ARGUMENT_ADDED', `${type}.${field}(${arg}:)`],
+  },
+  [DangerousChangeType.IMPLEMENTED_INTERFACE_ADDED]: {
+    pattern: /^\w+ added to interfaces implemented by (\w+)\.$/,
+    change: ([, type]) => ['IMPLEMENTED_INTERFACE_ADDED', type],
+  },
+  [DangerousChangeType.ARG_DEFAULT_VALUE_CHANGE]: {
+    pattern: /^(\w+)\.(\w+) arg (\w+) (?:has changed defaultValue|defaultValue was removed)/,
+    change: ([, type, field, arg]) => ['ARGUMENT_DEFAULT_VALUE_CHANGED', `${type}.${field}(${arg}:)`],
+  },
+};
+
+const severityOrder: SchemaChangeSeverity[] = ['BREAKING', 'DANGEROUS', 'SAFE'];
+
+/**
+ * Computes the changes between 2 versions of a schema, classifying each of them as breaking, dangerous or safe for
+ * existing clients.
+ *
+ * Breaking and dangerous changes are the ones reported by graphql-js `findBreakingChanges` and `findDangerousChanges`
+ * (and use their messages), to which are added the changes graphql-js does not report but that are safe for clients:
+ * added types, fields, directives and directive locations, and field, argument and input field type changes that only
+ * change nullability in a compatible way.
+ *
+ * Built-in types and directives are ignored. Changes are sorted by severity (breaking ones first), then by coordinate.
+ */
+export function diffSchemas(oldSchema: Schema, newSchema: Schema): SchemaChange[] {
+  const oldGraphQLSchema = oldSchema.toGraphQLJSSchema();
+  const newGraphQLSchema = newSchema.toGraphQLJSSchema();
+  const changes: SchemaChange[] = [];
+  const addGraphQLJSChanges = (graphQLJSChanges: (BreakingChange | DangerousChange)[], severity: SchemaChangeSeverity) => {
+    for (const { type, description } of graphQLJSChanges) {
+      // graphql-js also reports the built-in scalars that are no longer referenced, which are not changes of the schema.
+      if (description.startsWith('Standard scalar ')) {
+        continue;
+      }
+      const parser = graphQLJSChangeParsers[type];
+      const match = description.match(parser.pattern);
+      assert(match, () => `Unexpected description "${description}" for graphql-js schema change ${type}`);
+      const [kind, coordinate] = parser.change(match, oldSchema);
+      changes.push({ kind, severity, coordinate, message: description });
+    }
+  };
+  addGraphQLJSChanges(findBreakingChanges(oldGraphQLSchema, newGraphQLSchema), 'BREAKING');
+  addGraphQLJSChanges(findDangerousChanges(oldGraphQLSchema, newGraphQLSchema), 'DANGEROUS');
+  addSafeChanges(oldSchema, newSchema, changes);
+
+  return changes.sort((c1, c2) => {
+    const bySeverity = severityOrder.indexOf(c1.severity) - severityOrder.indexOf(c2.severity);
+    if (bySeverity !== 0) {
+      return bySeverity;
+    }
+    return c1.coordinate === c2.coordinate ? 0 : c1.coordinate < c2.coordinate ? -1 : 1;
+  });
+}
+
+function addSafeChanges(oldSchema: Schema, newSchema: Schema, changes: SchemaChange[]) {
+  // The type changes reported by graphql-js are the unsafe ones, so any other type change is safe.
+  const reported = new Set(changes.map((c) => `${c.kind}:${c.coordinate}`));
+  const add = (kind: SchemaChangeKind, coordinate: string, message: string) => {
+    if (!reported.has(`${kind}:${coordinate}`)) {
+      changes.push({ kind, severity: 'SAFE', coordinate, message });
+    }
+  };
+
+  for (const newType of newSchema.types()) {
+    const oldType = oldSchema.type(newType.name);
+    if (!oldType) {
+      add('TYPE_ADDED', newType.coordinate, `${newType.name} was added.`);
+    } else if (oldType.kind === newType.kind) {
+      switch (newType.kind) {
+        case 'ObjectType':
+        case 'InterfaceType':
+          addSafeFieldChanges(oldType as ObjectType | InterfaceType, newType, add);
+          break;
+        case 'InputObjectType':
+          for (const newField of newType.fields()) {
+            const oldField = (oldType as InputObjectType).field(newField.name);
+            if (oldField && oldField.type!.toString() !== newField.type!.toString()) {
+              add('INPUT_FIELD_TYPE_CHANGED', newField.coordinate, `${newField.coordinate} changed type from ${oldField.type} to ${newField.type}.`);
+            }
+          }
+          break;
+      }
+    }
+  }
+
+  for (const newDirective of newSchema.directives()) {
+    const oldDirective = oldSchema.directive(newDirective.name);
+    if (!oldDirective || oldDirective.isBuiltIn) {
+      add('DIRECTIVE_ADDED', newDirective.coordinate, `${newDirective.name} was added.`);
+    } else {
+      addSafeDirectiveChanges(oldDirective, newDirective, add);
+    }
+  }
+}
+
+type SafeChangeAdder = (kind: SchemaChangeKind, coordinate: string, message: string) => void;
+
+function addSafeFieldChanges(oldType: ObjectType | InterfaceType, newType: ObjectType | InterfaceType, add: SafeChangeAdder) {
+  for (const newField of newType.fields()) {
+    const oldField = oldType.field(newField.name);
+    if (!oldField) {
+      add('FIELD_ADDED', newField.coordinate, `${newField.coordinate} was added.`);
+      continue;
+    }
+    if (oldField.type!.toString() !== newField.type!.toString()) {
+      add('FIELD_TYPE_CHANGED', newField.coordinate, `${newField.coordinate} changed type from ${oldField.type} to ${newField.type}.`);
+    }
+    addSafeArgumentTypeChanges(oldField, newField, add);
+  }
+}
+
+function addSafeArgumentTypeChanges(oldField: FieldDefinition<any>, newField: FieldDefinition<any>, add: SafeChangeAdder) {
+  for (const newArg of newField.arguments()) {
+    const oldArg = oldField.argument(newArg.name);
+    if (oldArg && oldArg.type!.toString() !== newArg.type!.toString()) {
+      add('ARGUMENT_TYPE_CHANGED', newArg.coordinate, `${newField.coordinate} arg ${newArg.name} has changed type from ${oldArg.type} to ${newArg.type}.`);
+    }
+  }
+}
+
+function addSafeDirectiveChanges(oldDirective: DirectiveDefinition, newDirective: DirectiveDefinition, add: SafeChangeAdder) {
+  // graphql-js reports added required arguments but not optional ones, which are safe. Argument type changes of directives
+  // are not reported by graphql-js and are not considered safe either, so they are left out.
+  for (const newArg of newDirective.arguments()) {
+    if (!oldDirective.argument(newArg.name) && !newArg.isRequired()) {
+      add('ARGUMENT_ADDED', newArg.coordinate, `An optional arg ${newArg.name} on directive ${newDirective.name} was added.`);
+    }
+  }
+  for (const location of newDirective.locations) {
+    if (!oldDirective.locations.includes(location)) {
+      add('DIRECTIVE_LOCATION_ADDED', newDirective.coordinate, `${location} was added to ${newDirective.name}.`);
+    }
+  }
+}
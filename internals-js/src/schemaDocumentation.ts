@@ -0,0 +1,233 @@
+import {
+  ArgumentDefinition,
+  baseType,
+  DirectiveDefinition,
+  EnumValue,
+  FieldDefinition,
+  InputFieldDefinition,
+  NamedType,
+  Schema,
+  Type,
+} from "./definitions";
+import { deprecationReason } from "./deprecations";
+import { valueToString } from "./values";
+
+export type DocumentationFormat = 'markdown' | 'html';
+
+export type DocumentationOptions = {
+  format?: DocumentationFormat,
+  title?: string,
+}
+
+type Entry = {
+  name: string,
+  type?: Type,
+  description?: string,
+  deprecationReason?: string,
+  arguments: ArgumentDefinition<any>[],
+}
+
+type Section = {
+  // The anchor of the section, which other sections link to.
+  anchor: string,
+  title: string,
+  kind: string,
+  description?: string,
+  // Types implemented by the type (for object types and interfaces), or members (for unions).
+  related: { label: string, types: NamedType[] } | undefined,
+  entriesLabel: string,
+  entries: Entry[],
+}
+
+const KIND_LABELS: { [kind in NamedType['kind']]: string } = {
+  ScalarType: 'Scalar',
+  ObjectType: 'Object type',
+  InterfaceType: 'Interface',
+  UnionType: 'Union',
+  EnumType: 'Enum',
+  InputObjectType: 'Input object',
+};
+
+function entryOf(element: FieldDefinition<any> | InputFieldDefinition | EnumValue): Entry {
+  return {
+    name: element.name,
+    type: element instanceof EnumValue ? undefined : element.type,
+    description: element.description,
+    deprecationReason: deprecationReason(element),
+    arguments: element instanceof FieldDefinition ? element.arguments().concat() : [],
+  };
+}
+
+function typeSection(type: NamedType): Section {
+  let related: Section['related'] = undefined;
+  let entries: Entry[] = [];
+  let entriesLabel = 'Fields';
+  switch (type.kind) {
+    case 'ObjectType':
+    case 'InterfaceType':
+      const interfaces = type.interfaces();
+      related = interfaces.length > 0 ? { label: 'Implements', types: interfaces } : undefined;
+      entries = type.fields().map(entryOf);
+      break;
+    case 'UnionType':
+      related = { label: 'Members', types: type.types() };
+      break;
+    case 'EnumType':
+      entriesLabel = 'Values';
+      entries = type.values.map(entryOf);
+      break;
+    case 'InputObjectType':
+      entries = type.fields().map(entryOf);
+      break;
+  }
+  return {
+    anchor: type.name,
+    title: type.name,
+    kind: KIND_LABELS[type.kind],
+    description: type.description,
+    related,
+    entriesLabel,
+    entries,
+  };
+}
+
+function directiveSection(directive: DirectiveDefinition): Section {
+  return {
+    anchor: `directive-${directive.name}`,
+    title: `@${directive.name}`,
+    kind: `Directive${directive.repeatable ? ' (repeatable)' : ''} on ${directive.locations.join(', ')}`,
+    description: directive.description,
+    related: undefined,
+    entriesLabel: 'Arguments',
+    entries: directive.arguments().map((arg) => ({
+      name: arg.name,
+      type: arg.type,
+      description: arg.description,
+      deprecationReason: deprecationReason(arg),
+      arguments: [],
+    })),
+  };
+}
+
+interface Renderer {
+  title(title: string): string;
+  heading(title: string): string;
+  section(section: Section, typeLink: (type: Type) => string): string;
+}
+
+const markdownRenderer: Renderer = {
+  title: (title) => `# ${title}`,
+  heading: (title) => `## ${title}`,
+  section: (section, typeLink) => {
+    const escapeCell = (text: string) => text.replace(/\|/g, '\\|').replace(/\r?\n/g, '<br>');
+    const lines = [`### <a id="${section.anchor}"></a>${section.title}`, '', `*${section.kind}*`];
+    if (section.related) {
+      lines.push('', `${section.related.label}: ${section.related.types.map((t) => typeLink(t)).join(', ')}`);
+    }
+    if (section.description) {
+      lines.push('', section.description);
+    }
+    if (section.entries.length > 0) {
+      const hasTypes = section.entries.some((e) => e.type);
+      lines.push('', hasTypes ? `| ${section.entriesLabel} | Type | Description |` : `| ${section.entriesLabel} | Description |`);
+      lines.push(hasTypes ? '| --- | --- | --- |' : '| --- | --- |');
+      for (const entry of section.entries) {
+        const details = [entry.description ? escapeCell(entry.description) : undefined];
+        if (entry.deprecationReason) {
+          details.push(`**Deprecated**: ${escapeCell(entry.deprecationReason)}`);
+        }
+        if (entry.arguments.length > 0) {
+          details.push('Arguments: ' + entry.arguments.map((arg) => {
+            const defaultValue = arg.defaultValue !== undefined ? ` = ${escapeCell(valueToString(arg.defaultValue, arg.type))}` : '';
+            return `\`${arg.name}\`: ${typeLink(arg.type!)}${defaultValue}${arg.description ? ` (${escapeCell(arg.description)})` : ''}`;
+          }).join(', '));
+        }
+        const description = details.filter((d) => d !== undefined).join('<br>');
+        lines.push(hasTypes
+          ? `| \`${entry.name}\` | ${entry.type ? typeLink(entry.type) : ''} | ${description} |`
+          : `| \`${entry.name}\` | ${description} |`
+        );
+      }
+    }
+    return lines.join('\n');
+  },
+};
+
+function escapeHTML(text: string): string {
+  return text.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/"/g, '&quot;');
+}
+
+const htmlRenderer: Renderer = {
+  title: (title) => `<h1>${escapeHTML(title)}</h1>`,
+  heading: (title) => `<h2>${escapeHTML(title)}</h2>`,
+  section: (section, typeLink) => {
+    const lines = [
+      '<section>',
+      `<h3 id="${section.anchor}">${escapeHTML(section.title)}</h3>`,
+      `<p><em>${escapeHTML(section.kind)}</em></p>`,
+    ];
+    if (section.related) {
+      lines.push(`<p>${section.related.label}: ${section.related.types.map((t) => typeLink(t)).join(', ')}</p>`);
+    }
+    if (section.description) {
+      lines.push(`<p>${escapeHTML(section.description)}</p>`);
+    }
+    if (section.entries.length > 0) {
+      const hasTypes = section.entries.some((e) => e.type);
+      lines.push('<table>');
+      lines.push(`<tr><th>${section.entriesLabel}</th>${hasTypes ? '<th>Type</th>' : ''}<th>Description</th></tr>`);
+      for (const entry of section.entries) {
+        const details = [entry.description ? escapeHTML(entry.description) : undefined];
+        if (entry.deprecationReason) {
+          details.push(`<strong>Deprecated</strong>: ${escapeHTML(entry.deprecationReason)}`);
+        }
+        if (entry.arguments.length > 0) {
+          details.push('Arguments: ' + entry.arguments.map((arg) => {
+            const defaultValue = arg.defaultValue !== undefined ? ` = ${escapeHTML(valueToString(arg.defaultValue, arg.type))}` : '';
+            return `<code>${arg.name}</code>: ${typeLink(arg.type!)}${defaultValue}${arg.description ? ` (${escapeHTML(arg.description)})` : ''}`;
+          }).join(', '));
+        }
+        const description = details.filter((d) => d !== undefined).join('<br>');
+        lines.push(`<tr><td><code>${entry.name}</code></td>${hasTypes ? `<td>${entry.type ? typeLink(entry.type) : ''}</td>` : ''}<td>${description}</td></tr>`);
+      }
+      lines.push('</table>');
+    }
+    lines.push('</section>');
+    return lines.join('\n');
+  },
+};
+
+/**
+ * Renders reference documentation for the provided schema, in Markdown (the default) or HTML.
+ *
+ * The documentation has a section per (non built-in) type and directive, with an anchor named after the type
+ * or, for directives, `directive-<name>`. Sections list the fields, enum values or arguments of the element with their
+ * type, description and deprecation reason, and references to the types of the schema link to their section.
+ */
+export function schemaDocumentation(schema: Schema, options?: DocumentationOptions): string {
+  const renderer = options?.format === 'html' ? htmlRenderer : markdownRenderer;
+  const isHTML = renderer === htmlRenderer;
+  // Built-in types and the types of core features (which have no section) are not linked.
+  const isDocumented = (type: NamedType) => !type.isBuiltIn && !schema.coreFeatures?.sourceFeature(type);
+  const typeLink = (type: Type): string => {
+    const base = baseType(type);
+    const printed = isHTML ? `<code>${escapeHTML(type.toString())}</code>` : `\`${type}\``;
+    if (!isDocumented(base)) {
+      return printed;
+    }
+    return isHTML ? `<a href="#${base.name}">${printed}</a>` : `[${printed}](#${base.name})`;
+  };
+
+  const parts = [renderer.title(options?.title ?? 'Schema reference')];
+  const types = schema.types().filter(isDocumented);
+  if (types.length > 0) {
+    parts.push(renderer.heading('Types'));
+    types.forEach((type) => parts.push(renderer.section(typeSection(type), typeLink)));
+  }
+  const directives = schema.directives().filter((directive) => !schema.coreFeatures?.sourceFeature(directive));
+  if (directives.length > 0) {
+    parts.push(renderer.heading('Directives'));
+    directives.forEach((directive) => parts.push(renderer.section(directiveSection(directive), typeLink)));
+  }
+  return parts.join('\n\n') + '\n';
+}
@@ -0,0 +1,67 @@
+/**
+ * Node.js-only helpers to read schemas from files.
+ *
+ * This module is not re-exported by the package index, so that using `@apollo/federation-internals` (and in particular
+ * parsing) does not require Node.js built-in modules. Import it as `@apollo/federation-internals/dist/schemaFiles`.
+ */
+import { concatAST, DocumentNode, GraphQLError, Source } from "graphql";
+import fs from "fs";
+import path from "path";
+import { aggregateError, ERRORS } from "./error";
+import { ParserOptions, parseWithLimits } from "./parsing";
+
+const SCHEMA_FILE_EXTENSIONS = ['.graphql', '.gql'];
+
+// Symbolic links are followed, but every file or directory is only visited once (based on its real path), so that links to
+// a parent directory neither loop forever nor load the same files twice.
+function collectSchemaFiles(filePath: string, collected: string[], visited: Set<string>) {
+  const realPath = fs.realpathSync(filePath);
+  if (visited.has(realPath)) {
+    return;
+  }
+  visited.add(realPath);
+  if (fs.statSync(realPath).isDirectory()) {
+    const entries = fs.readdirSync(filePath).sort();
+    for (const entry of entries) {
+      const entryPath = path.join(filePath, entry);
+      if (fs.statSync(entryPath).isDirectory() || SCHEMA_FILE_EXTENSIONS.includes(path.extname(entry))) {
+        collectSchemaFiles(entryPath, collected, visited);
+      }
+    }
+  } else {
+    collected.push(filePath);
+  }
+}
+
+/**
+ * Reads and parses the provided SDL files into a single document (see `parseSources`). Any directory provided is searched
+ * recursively for `.graphql` and `.gql` files, which are read in alphabetical order. Symbolic links are followed, but a
+ * given file is only read once.
+ *
+ * All the files are parsed before any error is reported, and if any of them fails to parse, the thrown error aggregates the
+ * errors of every file.
+ */
+export function parseSchemaFiles(paths: string | readonly string[], options?: ParserOptions): DocumentNode {
+  const files: string[] = [];
+  const visited = new Set<string>();
+  for (const filePath of typeof paths === 'string' ? [paths] : paths) {
+    collectSchemaFiles(filePath, files, visited);
+  }
+
+  const documents: DocumentNode[] = [];
+  const errors: GraphQLError[] = [];
+  for (const file of files) {
+    try {
+      documents.push(parseWithLimits(new Source(fs.readFileSync(file, 'utf8'), file), options));
+    } catch (e) {
+      if (!(e instanceof GraphQLError)) {
+        throw e;
+      }
+      errors.push(e);
+    }
+  }
+  if (errors.length > 0) {
+    throw aggregateError(ERRORS.INVALID_GRAPHQL.code, `Failed to parse ${errors.length} of ${files.length} schema files`, errors);
+  }
+  return concatAST(documents);
+}
@@ -0,0 +1,74 @@
+import { Kind, DocumentNode, visit } from "graphql";
+import { Schema } from "./definitions";
+
+export type SchemaStats = {
+  // The number of type definitions (type extensions are not counted).
+  types: number,
+  // The number of fields of object types, interfaces and input objects (including those defined in extensions).
+  fields: number,
+  // The number of arguments of fields and directive definitions.
+  arguments: number,
+  enumValues: number,
+  directiveDefinitions: number,
+  directiveApplications: number,
+  // The total size, in bytes (of the UTF-8 encoding), of all descriptions.
+  descriptionBytes: number,
+}
+
+const TYPE_DEFINITION_KINDS: readonly Kind[] = [
+  Kind.SCALAR_TYPE_DEFINITION,
+  Kind.OBJECT_TYPE_DEFINITION,
+  Kind.INTERFACE_TYPE_DEFINITION,
+  Kind.UNION_TYPE_DEFINITION,
+  Kind.ENUM_TYPE_DEFINITION,
+  Kind.INPUT_OBJECT_TYPE_DEFINITION,
+];
+
+/**
+ * Computes statistics on the size of the provided schema (or schema document), to track the evolution of schemas over
+ * time for instance.
+ */
+export function schemaStats(schemaOrDocument: Schema | DocumentNode): SchemaStats {
+  const document = schemaOrDocument instanceof Schema ? schemaOrDocument.toAST() : schemaOrDocument;
+  const stats: SchemaStats = {
+    types: 0,
+    fields: 0,
+    arguments: 0,
+    enumValues: 0,
+    directiveDefinitions: 0,
+    directiveApplications: 0,
+    descriptionBytes: 0,
+  };
+  const encoder = new TextEncoder();
+  visit(document, {
+    enter(node) {
+      if ('description' in node && node.description) {
+        stats.descriptionBytes += encoder.encode(node.description.value).length;
+      }
+      if (TYPE_DEFINITION_KINDS.includes(node.kind)) {
+        stats.types++;
+      }
+      switch (node.kind) {
+        case Kind.FIELD_DEFINITION:
+          stats.fields++;
+          stats.arguments += node.arguments?.length ?? 0;
+          break;
+        case Kind.INPUT_OBJECT_TYPE_DEFINITION:
+        case Kind.INPUT_OBJECT_TYPE_EXTENSION:
+          stats.fields += node.fields?.length ?? 0;
+          break;
+        case Kind.ENUM_VALUE_DEFINITION:
+          stats.enumValues++;
+          break;
+        case Kind.DIRECTIVE_DEFINITION:
+          stats.directiveDefinitions++;
+          stats.arguments += node.arguments?.length ?? 0;
+          break;
+        case Kind.DIRECTIVE:
+          stats.directiveApplications++;
+          break;
+      }
+    },
+  });
+  return stats;
+}
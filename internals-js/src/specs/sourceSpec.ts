@@ -8,6 +8,7 @@ import {
   ListType,
   DirectiveDefinition,
   SchemaElement,
+  ObjectType,
 } from '../definitions';
 import { registerKnownFeature } from '../knownCoreFeatures';
 import { createDirectiveSpecification } from '../directiveAndTypeSpecification';
@@ -470,10 +471,11 @@ export class SourceSpecDefinition extends FeatureDefinition {
             { nodes: application.sourceAST },
           ));
         } else {
-          const typeGrandparentName = typeGrandparent.sourceAST?.name.value;
+          // Note that we check the actual root types rather than names, as root types may not use their default name.
+          const parentType = typeGrandparent as ObjectType;
           if (
-            typeGrandparentName !== "Query" &&
-            typeGrandparentName !== "Mutation" &&
+            !parentType.isQueryRootType() &&
+            !parentType.isMutationRootType() &&
             typeGrandparent.appliedDirectivesOf("key").length === 0
           ) {
             errors.push(ERRORS.SOURCE_FIELD_NOT_ON_ROOT_OR_ENTITY_FIELD.err(
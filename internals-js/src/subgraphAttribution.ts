@@ -0,0 +1,61 @@
+import { DocumentNode } from "graphql";
+import { FieldDefinition } from "./definitions";
+import { operationFromDocument, SelectionSet } from "./operations";
+import { Supergraph } from "./supergraphs";
+
+export type FieldAttribution = {
+  // The response path of the field in the operation (using aliases when the field is aliased).
+  path: string[],
+  // The coordinate of the field definition, like `User.name`. The parent type is the one of the selection, so for fields
+  // selected on an interface, it is the interface.
+  coordinate: string,
+  // The names of the subgraphs that can resolve the field, sorted.
+  subgraphs: string[],
+}
+
+/**
+ * Validates the provided operation against the API schema of the provided supergraph (throwing on validation errors, like
+ * `operationFromDocument`), and returns, for every field it selects, the subgraphs that can resolve that field.
+ *
+ * This is a quick way to estimate how many subgraphs an operation fans out to, without computing a query plan. A
+ * subgraph can resolve a field if it defines it and the field is not `@external` there. In particular, `@external` fields
+ * that are only available through a `@provides` are not attributed to the providing subgraph. `__typename` fields are
+ * resolved by every subgraph and are not included.
+ */
+export function attributeOperationFields(supergraph: Supergraph, document: DocumentNode, operationName?: string): FieldAttribution[] {
+  const operation = operationFromDocument(supergraph.apiSchema(), document, { operationName, validate: true });
+  const subgraphs = supergraph.subgraphs().values();
+  const subgraphsByCoordinate = new Map<string, string[]>();
+  const subgraphsOf = (definition: FieldDefinition<any>): string[] => {
+    let names = subgraphsByCoordinate.get(definition.coordinate);
+    if (!names) {
+      names = subgraphs
+        .filter((subgraph) => {
+          const field = subgraph.schema.elementByCoordinate(definition.coordinate);
+          return field instanceof FieldDefinition && !subgraph.metadata().isFieldExternal(field);
+        })
+        .map((subgraph) => subgraph.name);
+      subgraphsByCoordinate.set(definition.coordinate, names);
+    }
+    return names;
+  };
+
+  const attributions: FieldAttribution[] = [];
+  const collect = (selectionSet: SelectionSet, parentPath: string[]) => {
+    for (const selection of selectionSet.selections()) {
+      let path = parentPath;
+      if (selection.kind === 'FieldSelection') {
+        const field = selection.element;
+        path = parentPath.concat(field.responseName());
+        if (field.name !== '__typename') {
+          attributions.push({ path, coordinate: field.definition.coordinate, subgraphs: subgraphsOf(field.definition) });
+        }
+      }
+      if (selection.selectionSet) {
+        collect(selection.selectionSet, path);
+      }
+    }
+  };
+  collect(operation.selectionSet, []);
+  return attributions;
+}
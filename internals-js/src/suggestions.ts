@@ -50,3 +50,13 @@ export function didYouMean(suggestions: readonly string[]): string {
   return message + selected.join(', ') + ', or ' + lastItem + '?';
 }
 
+/**
+ * Appends the provided suggestions (see `didYouMean`) to an error message, adding a period to end the message first if it
+ * doesn't have one. The message is returned unchanged if there is no suggestion.
+ */
+export function withSuggestions(message: string, suggestions: readonly string[]): string {
+  if (suggestions.length === 0) {
+    return message;
+  }
+  return (message.endsWith('.') ? message : message + '.') + didYouMean(suggestions);
+}
@@ -0,0 +1,49 @@
+import { baseType, NamedSchemaElement } from "./definitions";
+import { Operation } from "./operations";
+
+export type SurrogateKeysOptions = {
+  // The name of the directive holding cache tags. Defaults to `cacheTag`.
+  directiveName?: string,
+  // The (string) argument of the directive holding the tag. Defaults to `name`.
+  argumentName?: string,
+}
+
+/**
+ * Computes the surrogate keys the response to the provided operation should carry, which is the union of the cache tags of
+ * every field resolved by the operation.
+ *
+ * Cache tags are declared by applying a cache tag directive (`@cacheTag(name: "...")` by default) on field definitions or on
+ * type definitions. A field contributes the tags applied on its definition, on the type declaring it and on the type it returns.
+ *
+ * The keys are returned sorted and de-duplicated. If the schema the operation is defined against has no cache tag directive,
+ * then no key is returned.
+ */
+export function surrogateKeys(operation: Operation, options: SurrogateKeysOptions = {}): string[] {
+  const directiveName = options.directiveName ?? 'cacheTag';
+  const argumentName = options.argumentName ?? 'name';
+  if (!operation.schema.directive(directiveName)) {
+    return [];
+  }
+
+  const keys = new Set<string>();
+  const collectTags = (element: NamedSchemaElement<any, any, any>) => {
+    for (const application of element.appliedDirectivesOf(directiveName)) {
+      const tag = application.arguments()[argumentName];
+      if (typeof tag === 'string') {
+        keys.add(tag);
+      }
+    }
+  };
+
+  collectTags(operation.selectionSet.parentType);
+  operation.selectionSet.forEachElement((element) => {
+    if (element.kind !== 'Field') {
+      return;
+    }
+    const definition = element.definition;
+    collectTags(definition);
+    collectTags(definition.parent);
+    collectTags(baseType(definition.type!));
+  });
+  return Array.from(keys).sort();
+}
@@ -276,6 +276,14 @@ class Validator {
           { nodes: sourceASTs(field) },
         );
       }
+      if (type.isOneOf()) {
+        if (isNonNullType(field.type!)) {
+          this.addError(`OneOf input field ${field.coordinate} must be nullable.`, { nodes: sourceASTs(field) });
+        }
+        if (field.defaultValue !== undefined) {
+          this.addError(`OneOf input field ${field.coordinate} cannot have a default value.`, { nodes: sourceASTs(field) });
+        }
+      }
     }
   }
 
@@ -520,6 +520,13 @@ export function isValidValueApplication(value: any, locationType: InputType, loc
     if (typeof value !== 'object') {
       return false;
     }
+    if (locationType.isOneOf()) {
+      // Exactly one field of a OneOf input object must be provided, and it must not be null.
+      const keys = Object.keys(value);
+      if (keys.length !== 1 || value[keys[0]] === null) {
+        return false;
+      }
+    }
     const valueKeys = new Set(Object.keys(value));
     const fieldsAreValid = locationType.fields().every(field => {
       valueKeys.delete(field.name);
@@ -5826,6 +5826,130 @@ describe('mutations', () => {
   });
 });
 
+describe('custom root type names', () => {
+  it('plans operations against subgraphs with non-default root type names', () => {
+    const subgraph1 = {
+      name: 'Subgraph1',
+      typeDefs: gql`
+        schema {
+          query: RootQuery
+          mutation: RootMutation
+        }
+
+        type RootQuery {
+          me: User
+        }
+
+        type RootMutation {
+          updateName(name: String!): User
+        }
+
+        type User @key(fields: "id") {
+          id: ID!
+          name: String
+        }
+      `,
+    };
+
+    const subgraph2 = {
+      name: 'Subgraph2',
+      typeDefs: gql`
+        type User @key(fields: "id") {
+          id: ID!
+          reviews: [String]
+        }
+      `,
+    };
+
+    const [api, queryPlanner] = composeAndCreatePlanner(subgraph1, subgraph2);
+    let operation = operationFromDocument(
+      api,
+      gql`
+        {
+          me {
+            name
+            reviews
+          }
+        }
+      `,
+    );
+
+    let plan = queryPlanner.buildQueryPlan(operation);
+    expect(plan).toMatchInlineSnapshot(`
+      QueryPlan {
+        Sequence {
+          Fetch(service: "Subgraph1") {
+            {
+              me {
+                __typename
+                id
+                name
+              }
+            }
+          },
+          Flatten(path: "me") {
+            Fetch(service: "Subgraph2") {
+              {
+                ... on User {
+                  __typename
+                  id
+                }
+              } =>
+              {
+                ... on User {
+                  reviews
+                }
+              }
+            },
+          },
+        },
+      }
+    `);
+
+    operation = operationFromDocument(
+      api,
+      gql`
+        mutation {
+          updateName(name: "foo") {
+            reviews
+          }
+        }
+      `,
+    );
+
+    plan = queryPlanner.buildQueryPlan(operation);
+    expect(plan).toMatchInlineSnapshot(`
+      QueryPlan {
+        Sequence {
+          Fetch(service: "Subgraph1") {
+            {
+              updateName(name: "foo") {
+                __typename
+                id
+              }
+            }
+          },
+          Flatten(path: "updateName") {
+            Fetch(service: "Subgraph2") {
+              {
+                ... on User {
+                  __typename
+                  id
+                }
+              } =>
+              {
+                ... on User {
+                  reviews
+                }
+              }
+            },
+          },
+        },
+      }
+    `);
+  });
+});
+
 describe('interface type-explosion', () => {
   test('handles non-matching value types under interface field', () => {
     const subgraph1 = {